@@ -3,26 +3,28 @@ use rscenes::prelude::*;
 use std::f32::consts::TAU;
 
 const FOE_SPEED: f32 = 500.0;
+static BALL: &[u8] = include_bytes!("assets/ball_red_large.png");
+static PLUCKS: [&[u8]; 2] = [
+    include_bytes!("assets/pluck_001.ogg"),
+    include_bytes!("assets/pluck_002.ogg"),
+];
 
 #[derive(Debug)]
 pub struct Foe {
-    pub ball: Image,
+    pub ball: OwnedImage,
+    pub texture: Option<Asset<Texture2D>>,
     pub x: f32,
     pub y: f32,
     pub radius: f32,
     pub movement: Vector2,
-    pub pluck_sounds: Vec<Sound>,
+    pub pluck_sounds: Vec<Asset<Sound>>,
 }
 
 impl Foe {
-    pub fn draw(&self, connector: Connector2D) -> Result<(), String> {
-        connector.draw_texture(
-            Texture2D::load_from_image(self.ball)?,
-            self.x as i32,
-            self.y as i32,
-            Color::WHITE,
-        );
-        Ok(())
+    pub fn draw(&self, connector: Connector2D) {
+        if let Some(texture) = &self.texture {
+            connector.draw_texture(texture.get(), self.x as i32, self.y as i32, Color::WHITE);
+        }
     }
 
     pub fn update(&mut self, connector: PlainConnector, dt: f32) {
@@ -31,8 +33,8 @@ impl Foe {
 
         let min_x = 0.0;
         let min_y = 0.0;
-        let max_x = (connector.get_render_width() - self.ball.width) as f32;
-        let max_y = (connector.get_render_height() - self.ball.height) as f32;
+        let max_x = (connector.get_render_width() - self.ball.as_ref().width) as f32;
+        let max_y = (connector.get_render_height() - self.ball.as_ref().height) as f32;
 
         let mut play = false;
 
@@ -61,19 +63,24 @@ impl Foe {
             let pluck = self
                 .pluck_sounds
                 .get(random::<usize>() % self.pluck_sounds.len())
-                .unwrap();
+                .unwrap()
+                .get();
             if pluck.is_ready() {
                 pluck.play();
             }
         }
     }
 
-    pub fn setup(&mut self, connector: PlainConnector) -> Result<(), String> {
+    pub fn setup(
+        &mut self,
+        connector: PlainConnector,
+        assets: &mut AssetCache,
+    ) -> Result<(), RaylibError> {
         if self.pluck_sounds.is_empty() {
-            let width = (connector.get_render_width() - self.ball.width) as f32;
-            let height = (connector.get_render_height() - self.ball.height) as f32;
-            let min_x = self.ball.width as f32 / 2.0;
-            let min_y = self.ball.height as f32 / 2.0;
+            let width = (connector.get_render_width() - self.ball.as_ref().width) as f32;
+            let height = (connector.get_render_height() - self.ball.as_ref().height) as f32;
+            let min_x = self.ball.as_ref().width as f32 / 2.0;
+            let min_y = self.ball.as_ref().height as f32 / 2.0;
             self.x = random::<f32>() * width + min_x;
             self.y = random::<f32>() * height + min_y;
             let angle: f32 = random::<f32>() * TAU;
@@ -82,15 +89,11 @@ impl Foe {
                 y: angle.sin() * FOE_SPEED,
             };
 
-            let data = include_bytes!("assets/pluck_001.ogg");
-            let wave = Wave::load_from_memory(WaveType::Ogg, data)?;
-            self.pluck_sounds.push(Sound::load_from_wave(wave));
-            wave.unload();
-
-            let data = include_bytes!("assets/pluck_002.ogg");
-            let wave = Wave::load_from_memory(WaveType::Ogg, data)?;
-            self.pluck_sounds.push(Sound::load_from_wave(wave));
-            wave.unload();
+            self.texture = Some(assets.texture_from_memory(ImageType::Png, BALL)?);
+            for data in PLUCKS {
+                self.pluck_sounds
+                    .push(assets.sound_from_memory(WaveType::Ogg, data)?);
+            }
         }
 
         Ok(())
@@ -99,11 +102,11 @@ impl Foe {
 
 impl Default for Foe {
     fn default() -> Self {
-        let data = include_bytes!("assets/ball_red_large.png");
-        let ball = Image::load_from_memory(ImageType::Png, data).unwrap();
-        let radius = (ball.width + ball.height) as f32 / 4.0;
+        let ball = OwnedImage::load_from_memory(ImageType::Png, BALL).unwrap();
+        let radius = (ball.as_ref().width + ball.as_ref().height) as f32 / 4.0;
         Self {
             ball,
+            texture: None,
             x: 0.0,
             y: 0.0,
             radius,
@@ -113,15 +116,6 @@ impl Default for Foe {
     }
 }
 
-impl Drop for Foe {
-    fn drop(&mut self) {
-        self.ball.unload();
-        for sound in self.pluck_sounds.iter_mut() {
-            sound.unload();
-        }
-    }
-}
-
 impl From<&Foe> for Vector2 {
     fn from(foe: &Foe) -> Self {
         Self {
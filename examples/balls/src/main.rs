@@ -6,18 +6,23 @@ use foe::Foe;
 use rscenes::prelude::*;
 
 fn main() {
-    TraceLogLevel::Error.set_default();
-
     let mut manager = Rscenes::default();
-    manager.title = "Rscenes Test".to_owned();
+    manager.config = Config {
+        title: "Rscenes Test".to_owned(),
+        audio: true,
+        log_level: TraceLogLevel::Error,
+        ..Default::default()
+    };
+    manager.fixed_timestep = Some(FixedTimestep::new(1.0 / 120.0));
+    manager.debug_overlay = Some(DebugOverlay::new());
     manager
         .set_init(Box::new(BallsScene {
             player: Player::default(),
             foes: (0..4).map(|_| Foe::default()).collect::<Vec<_>>(),
             game_over: false,
             collision_sound: None,
+            assets: AssetCache::new(),
         }))
-        .add_setup(setup!(|con| con.init_audio_device()))
         .start();
 }
 
@@ -26,28 +31,33 @@ struct BallsScene {
     player: Player,
     foes: Vec<Foe>,
     game_over: bool,
-    collision_sound: Option<Sound>,
+    collision_sound: Option<Asset<Sound>>,
+    assets: AssetCache,
 }
 
 impl Scene for BallsScene {
-    fn on_setup(&mut self, _: PlainConnector) -> Result<(), String> {
+    fn assets(&mut self) -> Option<&mut AssetCache> {
+        Some(&mut self.assets)
+    }
+
+    fn on_setup(&mut self, _: PlainConnector) -> Result<(), Error> {
         let data = include_bytes!("assets/impactBell_heavy_000.ogg");
-        let wave = Wave::load_from_memory(WaveType::Ogg, data)?;
-        self.collision_sound = Some(Sound::load_from_wave(wave));
+        self.collision_sound = Some(self.assets.sound_from_memory(WaveType::Ogg, data)?);
+        self.player.setup(&mut self.assets)?;
         Ok(())
     }
 
-    fn on_load(&mut self, rl: PlainConnector) -> Result<(), String> {
+    fn on_load(&mut self, rl: PlainConnector) -> Result<(), Error> {
         let screen = rl.get_render_rec();
-        self.player.x = (screen.width - self.player.ball.width as f32) / 2.0;
-        self.player.y = (screen.height - self.player.ball.height as f32) / 2.0;
+        self.player.x = (screen.width - self.player.ball.as_ref().width as f32) / 2.0;
+        self.player.y = (screen.height - self.player.ball.as_ref().height as f32) / 2.0;
         for foe in self.foes.iter_mut() {
-            foe.setup(rl)?;
+            foe.setup(rl, &mut self.assets)?;
         }
         Ok(())
     }
 
-    fn on_update(&mut self, rl: PlainConnector, dt: f32) -> Result<State, String> {
+    fn on_fixed_update(&mut self, rl: PlainConnector, dt: f32) -> Result<State, Error> {
         if !self.game_over {
             self.player.update(rl, dt)?;
         }
@@ -61,7 +71,8 @@ impl Scene for BallsScene {
             ) {
                 self.game_over = true;
                 self.player.x = -2.0 * self.player.radius;
-                if let Some(collision) = self.collision_sound {
+                if let Some(collision) = &self.collision_sound {
+                    let collision = collision.get();
                     if !collision.is_playing() {
                         collision.play();
                     }
@@ -88,11 +99,11 @@ impl Scene for BallsScene {
             rl.draw_fps(5, 5);
         }
         if !self.game_over {
-            self.player.draw(rl)?;
+            self.player.draw(rl);
         }
 
         for foe in self.foes.iter() {
-            foe.draw(rl)?;
+            foe.draw(rl);
         }
     }
 }
@@ -1,45 +1,39 @@
 use rscenes::prelude::*;
 
 const PLAYER_SPEED: f32 = 500.0;
+static BALL: &[u8] = include_bytes!("assets/ball_blue_large.png");
 
 #[derive(Debug)]
 pub struct Player {
-    pub ball: Image,
+    pub ball: OwnedImage,
+    pub texture: Option<Asset<Texture2D>>,
+    pub controls: InputMap,
     pub x: f32,
     pub y: f32,
     pub radius: f32,
 }
 
 impl Player {
-    pub fn draw(&self, connector: Connector2D) -> Result<(), String> {
-        connector.draw_texture(
-            Texture2D::load_from_image(self.ball)?,
-            self.x as i32,
-            self.y as i32,
-            Color::WHITE,
-        );
+    pub fn setup(&mut self, assets: &mut AssetCache) -> Result<(), RaylibError> {
+        self.texture = Some(assets.texture_from_memory(ImageType::Png, BALL)?);
         Ok(())
     }
 
-    pub fn update(&mut self, connector: PlainConnector, dt: f32) -> Result<(), String> {
-        self.r#move(dt);
+    pub fn draw(&self, connector: Connector2D) {
+        if let Some(texture) = &self.texture {
+            connector.draw_texture(texture.get(), self.x as i32, self.y as i32, Color::WHITE);
+        }
+    }
+
+    pub fn update(&mut self, connector: PlainConnector, dt: f32) -> Result<(), Error> {
+        self.r#move(connector, dt);
         self.constraint(connector);
         Ok(())
     }
 
-    fn r#move(&mut self, dt: f32) {
-        if KeyboardKey::Left.is_down() || KeyboardKey::A.is_down() {
-            self.x -= PLAYER_SPEED * dt;
-        }
-        if KeyboardKey::Right.is_down() || KeyboardKey::D.is_down() {
-            self.x += PLAYER_SPEED * dt;
-        }
-        if KeyboardKey::Up.is_down() || KeyboardKey::W.is_down() {
-            self.y -= PLAYER_SPEED * dt;
-        }
-        if KeyboardKey::Down.is_down() || KeyboardKey::S.is_down() {
-            self.y += PLAYER_SPEED * dt;
-        }
+    fn r#move(&mut self, connector: PlainConnector, dt: f32) {
+        self.x += self.controls.axis(connector, "horizontal") * PLAYER_SPEED * dt;
+        self.y += self.controls.axis(connector, "vertical") * PLAYER_SPEED * dt;
     }
 
     fn constraint(&mut self, connector: PlainConnector) {
@@ -47,8 +41,8 @@ impl Player {
         let height = connector.get_render_height();
         let min_x = 0.0;
         let min_y = 0.0;
-        let max_x = (width - self.ball.width) as f32;
-        let max_y = (height - self.ball.height) as f32;
+        let max_x = (width - self.ball.as_ref().width) as f32;
+        let max_y = (height - self.ball.as_ref().height) as f32;
 
         if self.x < min_x {
             self.x = min_x;
@@ -67,12 +61,27 @@ impl Player {
 
 impl Default for Player {
     fn default() -> Self {
-        let data = include_bytes!("assets/ball_blue_large.png");
-        let ball = Image::load_from_memory(ImageType::Png, data).unwrap();
-        let radius = (ball.width + ball.height) as f32 / 4.0;
+        let ball = OwnedImage::load_from_memory(ImageType::Png, BALL).unwrap();
+        let radius = (ball.as_ref().width + ball.as_ref().height) as f32 / 4.0;
+        let mut controls = InputMap::new();
+        controls
+            .bind_axis(
+                "horizontal",
+                Axis::buttons(KeyboardKey::Left, KeyboardKey::Right),
+            )
+            .bind_axis("horizontal", Axis::buttons(KeyboardKey::A, KeyboardKey::D))
+            .bind_axis("horizontal", Axis::Gamepad(0, GamepadAxis::LeftX))
+            .bind_axis(
+                "vertical",
+                Axis::buttons(KeyboardKey::Up, KeyboardKey::Down),
+            )
+            .bind_axis("vertical", Axis::buttons(KeyboardKey::W, KeyboardKey::S))
+            .bind_axis("vertical", Axis::Gamepad(0, GamepadAxis::LeftY));
 
         Self {
             ball,
+            texture: None,
+            controls,
             x: 0.0,
             y: 0.0,
             radius,
@@ -80,12 +89,6 @@ impl Default for Player {
     }
 }
 
-impl Drop for Player {
-    fn drop(&mut self) {
-        self.ball.unload();
-    }
-}
-
 impl From<&Player> for Vector2 {
     fn from(player: &Player) -> Self {
         Self {
@@ -27,30 +27,15 @@ impl SfxManager {
         }
     }
 
-    pub fn get_instance() -> Option<Sfx> {
+    pub fn get_instance(assets: &mut AssetCache) -> Option<Sfx> {
         unsafe {
             if let Some(sfx) = SFX {
-                let wave = Wave::load_from_memory(WaveType::Wav, sfx.clapping).ok()?;
-                let clapping = Sound::load_from_wave(wave);
-                wave.unload();
-                let wave = Wave::load_from_memory(WaveType::Wav, sfx.error).ok()?;
-                let error = Sound::load_from_wave(wave);
-                wave.unload();
-                let wave = Wave::load_from_memory(WaveType::Wav, sfx.lock).ok()?;
-                let lock = Sound::load_from_wave(wave);
-                wave.unload();
-                let wave = Wave::load_from_memory(WaveType::Wav, sfx.set).ok()?;
-                let set = Sound::load_from_wave(wave);
-                wave.unload();
-                let wave = Wave::load_from_memory(WaveType::Wav, sfx.unset).ok()?;
-                let unset = Sound::load_from_wave(wave);
-                wave.unload();
                 return Some(Sfx {
-                    clapping,
-                    error,
-                    lock,
-                    set,
-                    unset,
+                    clapping: assets.sound_from_memory(WaveType::Wav, sfx.clapping).ok()?,
+                    error: assets.sound_from_memory(WaveType::Wav, sfx.error).ok()?,
+                    lock: assets.sound_from_memory(WaveType::Wav, sfx.lock).ok()?,
+                    set: assets.sound_from_memory(WaveType::Wav, sfx.set).ok()?,
+                    unset: assets.sound_from_memory(WaveType::Wav, sfx.unset).ok()?,
                 });
             }
         }
@@ -58,7 +43,7 @@ impl SfxManager {
         None
     }
 
-    pub fn play(&self, tpe: SfxType) -> Result<(), String> {
+    pub fn play(&self, assets: &mut AssetCache, tpe: SfxType) -> Result<(), RaylibError> {
         let data = match tpe {
             SfxType::CLAPPING => self.clapping,
             SfxType::ERROR => self.error,
@@ -66,10 +51,7 @@ impl SfxManager {
             SfxType::SET => self.set,
             SfxType::UNSET => self.unset,
         };
-        let wave = Wave::load_from_memory(WaveType::Wav, data)?;
-        let sound = Sound::load_from_wave(wave);
-        wave.unload();
-        sound.play();
+        assets.sound_from_memory(WaveType::Wav, data)?.get().play();
         Ok(())
     }
 }
@@ -88,31 +70,21 @@ impl Default for SfxManager {
 
 #[derive(Debug)]
 pub struct Sfx {
-    clapping: Sound,
-    error: Sound,
-    lock: Sound,
-    set: Sound,
-    unset: Sound,
+    clapping: Asset<Sound>,
+    error: Asset<Sound>,
+    lock: Asset<Sound>,
+    set: Asset<Sound>,
+    unset: Asset<Sound>,
 }
 
 impl Sfx {
     pub fn play(&self, tpe: SfxType) {
         match tpe {
-            SfxType::CLAPPING => self.clapping.play(),
-            SfxType::ERROR => self.error.play(),
-            SfxType::LOCK => self.lock.play(),
-            SfxType::SET => self.set.play(),
-            SfxType::UNSET => self.unset.play(),
+            SfxType::CLAPPING => self.clapping.get().play(),
+            SfxType::ERROR => self.error.get().play(),
+            SfxType::LOCK => self.lock.get().play(),
+            SfxType::SET => self.set.get().play(),
+            SfxType::UNSET => self.unset.get().play(),
         }
     }
 }
-
-impl Drop for Sfx {
-    fn drop(&mut self) {
-        self.clapping.unload();
-        self.error.unload();
-        self.lock.unload();
-        self.set.unload();
-        self.unset.unload();
-    }
-}
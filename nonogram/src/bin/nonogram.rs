@@ -2,43 +2,43 @@ extern crate kodumaro_nonogram;
 
 use kodumaro_nonogram::*;
 use rscenes::{extras::XDGStore, prelude::*};
+use std::process;
 
 fn main() {
     // TraceLogLevel::Error.set_default();
     XDGStore::init_storage("nonogram").unwrap();
 
     let mut manager = Rscenes::default();
-    manager.title = "Nonogram".to_owned();
+    manager.error_policy = ErrorPolicy::CrashScreen;
 
-    let (width, height, fs) = match XDGStore::retrieve::<Persist>("nonogram", "window") {
-        Ok(value) => value,
-
-        _ => {
-            let (width, height) = manager.screen_size();
-            (width * 2 / 3, height * 2 / 3, false)
-        }
+    let (width, height) = manager.screen_size();
+    manager.config = Config {
+        title: "Nonogram".to_owned(),
+        size: (width * 2 / 3, height * 2 / 3),
+        min_size: Some((800, 600)),
+        fps: 30,
+        resizable: true,
+        exit_key: KeyboardKey::Null,
+        audio: true,
+        log_file: Some("nonogram/nonogram.log".into()),
+        crash_dir: Some("nonogram".into()),
+        ..Default::default()
     };
-    let (width, height) = (width.max(800), height.max(600));
-    manager.window_size = (width, height);
+    Geometry::restore(&mut manager.config);
+    if let Err(err) = manager.parse_args() {
+        eprintln!("{}\n\nOptions:\n{}", err, Options::USAGE);
+        process::exit(2);
+    }
+    let (width, height) = manager.config.size;
+    manager.config.size = (width.max(800), height.max(600));
 
     let mut scene = MainMenu::default();
-    scene.geom.x = width as f32;
-    scene.geom.y = height as f32;
+    scene.geom.x = manager.config.size.0 as f32;
+    scene.geom.y = manager.config.size.1 as f32;
     manager
         .set_init(Box::new(scene))
-        .add_setup(setup!(|rl| rl.set_exit_key(KeyboardKey::Null)))
-        .add_setup(setup!(|rl| rl.set_target_fps(30)))
-        .add_setup(setup!(|rl| rl.set_window_min_size(800, 600)))
-        .add_setup(setup!(|rl| rl.init_audio_device()))
-        .add_setup(setup!(move |rl| {
-            if fs {
-                rl.set_window_state(
-                    ConfigFlags::FullscreenMode as usize | ConfigFlags::WindowResizable as usize,
-                );
-            } else {
-                rl.set_window_state(ConfigFlags::WindowResizable as usize);
-            }
-        }))
+        // Boards come from raylib's seed, so recorded sessions replay the same puzzles
+        .add_setup(setup!(|rl| fastrand::seed(rl.get_random_value(0, i32::MAX) as u64)))
         .add_setup(setup!(|_rs| SfxManager::load_assets()))
         .start();
 }
@@ -4,6 +4,6 @@ mod persist;
 mod ui;
 
 pub use audio::SfxManager;
-pub use persist::Persist;
+pub use persist::Geometry;
 pub use ui::gameplay::Gameplay;
 pub use ui::main_menu::MainMenu;
@@ -1 +1,23 @@
-pub type Persist = (i32, i32, bool);
+use rscenes::{extras::XDGStore, prelude::*};
+use serde::{Deserialize, Serialize};
+
+/// Window geometry kept from one run to the next
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Geometry {
+    pub size: (i32, i32),
+    pub fullscreen: bool,
+}
+
+impl Geometry {
+    /// Start with the geometry of the last run, command-line options still overriding it
+    pub fn restore(config: &mut Config) {
+        if let Ok(geom) = XDGStore::retrieve::<Self>("nonogram", "window") {
+            config.size = geom.size;
+            config.fullscreen = geom.fullscreen;
+        }
+    }
+
+    pub fn save(self) -> Result<(), Error> {
+        XDGStore::save("nonogram", "window", self)
+    }
+}
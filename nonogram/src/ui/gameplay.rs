@@ -11,6 +11,8 @@ const BOLD_LINE: f32 = 4.0;
 
 #[derive(Debug)]
 pub struct Gameplay {
+    assets: AssetCache,
+    controls: InputMap,
     sfx: Option<Sfx>,
     board: Box<dyn Board>,
     hhints: Vec<String>,
@@ -22,9 +24,11 @@ pub struct Gameplay {
     vhints_rect: Rectangle,
     cell_size: Vector2,
     time_lapse: f32,
-    vic_index: f32,
+    vic_index: Tweened<f32>,
+    tweens: Tweens,
     mute: bool,
     done: bool,
+    focused: bool,
     mouse_position: (usize, usize),
     left_click: bool,
     right_click: bool,
@@ -60,6 +64,8 @@ impl Gameplay {
             })
             .collect::<Vec<String>>();
         Self {
+            assets: AssetCache::new(),
+            controls: controls(),
             sfx: None,
             board,
             size,
@@ -86,15 +92,42 @@ impl Gameplay {
             },
             cell_size: Vector2::ZERO,
             time_lapse: 0.0,
-            vic_index: 0.0,
+            vic_index: Tweened::default(),
+            tweens: Tweens::new(),
             mute: false,
             done: false,
+            focused: true,
             mouse_position: (0, 0),
             left_click: false,
             right_click: false,
         }
     }
 
+    fn layout(&mut self, screen: Rectangle) {
+        self.board_rect = Rectangle {
+            x: 0.0,
+            y: screen.height * 0.25,
+            width: screen.width * 0.75,
+            height: screen.height * 0.75,
+        };
+        self.cell_size = Vector2 {
+            x: self.board_rect.width / self.size.x,
+            y: self.board_rect.height / self.size.y,
+        };
+        self.hhints_rect = Rectangle {
+            x: self.cell_size.x * 0.5,
+            y: 0.0,
+            width: screen.width * 0.75,
+            height: screen.height * 0.25,
+        };
+        self.vhints_rect = Rectangle {
+            x: self.board_rect.x + self.board_rect.width + self.cell_size.x * 0.5,
+            y: self.board_rect.y,
+            width: screen.width * 0.25,
+            height: screen.height * 0.25,
+        };
+    }
+
     fn play(&self, sound: SfxType) {
         if !(self.mute) {
             if let Some(sfx) = &self.sfx {
@@ -306,56 +339,64 @@ impl Gameplay {
 }
 
 impl Scene for Gameplay {
-    fn on_load(&mut self, _: PlainConnector) -> Result<(), String> {
-        self.sfx = SfxManager::get_instance();
+    fn tweens(&mut self) -> Option<&mut Tweens> {
+        Some(&mut self.tweens)
+    }
+
+    fn on_setup(&mut self, rl: PlainConnector) -> Result<(), Error> {
+        // Started with --mute
+        self.mute = rl.get_master_volume() <= 0.0;
+        Ok(())
+    }
+
+    fn on_load(&mut self, rl: PlainConnector) -> Result<(), Error> {
+        self.sfx = SfxManager::get_instance(&mut self.assets);
         if self.sfx.is_none() {
             TraceLogLevel::Error.log("couldn't load sound effects");
         }
+        self.focused = rl.is_window_focused();
+        self.layout(rl.get_render_rec());
         Ok(())
     }
 
-    fn on_update(&mut self, rl: PlainConnector, dt: f32) -> Result<State, String> {
-        if KeyboardKey::F2.is_released() {
+    fn on_resize(&mut self, _: PlainConnector, size: Vector2) -> Result<(), Error> {
+        self.layout(Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: size.x,
+            height: size.y,
+        });
+        Ok(())
+    }
+
+    fn on_focus_changed(&mut self, _: PlainConnector, focused: bool) -> Result<(), Error> {
+        self.focused = focused;
+        Ok(())
+    }
+
+    fn on_update(&mut self, rl: PlainConnector, dt: f32) -> Result<State, Error> {
+        if self.controls.is_released(rl, "mute") {
             self.mute = !self.mute;
+            // F2 only mutes this game's effects, it just brings back the sound --mute took down
+            if !self.mute && rl.get_master_volume() <= 0.0 {
+                rl.set_master_volume(1.0);
+            }
         }
 
-        if KeyboardKey::F3.is_released()
-            || KeyboardKey::Pause.is_released() && !self.board.is_done()
+        if self.controls.is_released(rl, "menu")
+            || self.controls.is_released(rl, "pause") && !self.board.is_done()
         {
             return Ok(State::Next(Box::new(Pause)));
         }
 
-        if KeyboardKey::F.is_released() {
+        if self.controls.is_released(rl, "fullscreen") {
             rl.toggle_fullscreen();
         }
 
-        if KeyboardKey::H.is_released() {
+        if self.controls.is_released(rl, "highlight") {
             self.highlight = !self.highlight;
         }
 
-        let screen = rl.get_render_rec();
-        self.board_rect = Rectangle {
-            x: 0.0,
-            y: screen.height * 0.25,
-            width: screen.width * 0.75,
-            height: screen.height * 0.75,
-        };
-        self.cell_size = Vector2 {
-            x: self.board_rect.width / self.size.x,
-            y: self.board_rect.height / self.size.y,
-        };
-        self.hhints_rect = Rectangle {
-            x: self.cell_size.x * 0.5,
-            y: 0.0,
-            width: screen.width * 0.75,
-            height: screen.height * 0.25,
-        };
-        self.vhints_rect = Rectangle {
-            x: self.board_rect.x + self.board_rect.width + self.cell_size.x * 0.5,
-            y: self.board_rect.y,
-            width: screen.width * 0.25,
-            height: screen.height * 0.25,
-        };
         let mouse = rl.get_mouse_position();
         self.mouse_position = (
             (((mouse.x - self.hhints_rect.x) / self.cell_size.x) + 0.5).floor() as usize,
@@ -366,15 +407,15 @@ impl Scene for Gameplay {
             },
         );
 
-        let left_click = rl.is_mouse_button_released(MouseButton::Left);
-        let right_click = rl.is_mouse_button_released(MouseButton::Right);
-        let ctrl = KeyboardKey::LeftControl.is_down() || KeyboardKey::RightControl.is_down();
-        self.right_click = right_click || (left_click && ctrl);
-        self.left_click = left_click && !ctrl;
+        self.right_click = self.controls.is_released(rl, "mark");
+        self.left_click = self.controls.is_released(rl, "fill") && !self.right_click;
 
         if self.board.is_done() && !self.done {
             self.done = true;
             self.play(SfxType::CLAPPING);
+            let frames = VICTORY.len() as f32;
+            self.tweens
+                .play(Tween::new(&self.vic_index, frames, frames / 5.0).forever());
 
             for y in 0..(self.size.y as usize) {
                 for x in 0..(self.size.x as usize) {
@@ -385,9 +426,7 @@ impl Scene for Gameplay {
             }
         }
 
-        if self.done {
-            self.vic_index += dt * 5.0;
-        } else if rl.is_window_focused() {
+        if !self.done && self.focused {
             self.time_lapse += dt;
 
             for y in 0..(self.size.y as usize) {
@@ -427,7 +466,7 @@ impl Scene for Gameplay {
             }
         }
 
-        if KeyboardKey::Escape.is_released() {
+        if self.controls.is_released(rl, "back") {
             Ok(State::Prev(1))
         } else {
             Ok(State::Keep)
@@ -501,7 +540,7 @@ impl Scene for Gameplay {
                     }
                 }
             }
-            let text = VICTORY[self.vic_index as usize % VICTORY.len()];
+            let text = VICTORY[self.vic_index.get() as usize % VICTORY.len()];
             let size = rl.measure_text(text, 240) as f32;
             let shadow = Color::DARKGREEN.fade(0.8);
             rl.draw_text_ex(
@@ -575,3 +614,150 @@ fn monospace(
         );
     }
 }
+
+fn controls() -> InputMap {
+    let mut controls = InputMap::new();
+    controls
+        .bind("mute", KeyboardKey::F2)
+        .bind("menu", KeyboardKey::F3)
+        .bind("pause", KeyboardKey::Pause)
+        .bind("fullscreen", KeyboardKey::F)
+        .bind("highlight", KeyboardKey::H)
+        .bind("fill", MouseButton::Left)
+        .bind("mark", MouseButton::Right)
+        .bind(
+            "mark",
+            [
+                Input::Key(KeyboardKey::LeftControl),
+                Input::Mouse(MouseButton::Left),
+            ],
+        )
+        .bind(
+            "mark",
+            [
+                Input::Key(KeyboardKey::RightControl),
+                Input::Mouse(MouseButton::Left),
+            ],
+        )
+        .bind("back", KeyboardKey::Escape);
+    controls
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::BoardStruct;
+    use rscenes::extras::Headless;
+
+    fn gameplay() -> Gameplay {
+        let mut board = BoardStruct::<5, 5>::default();
+        board.hhints[0] = vec![1];
+        board.vhints[0] = vec![1];
+        Gameplay::new(Box::new(board), false)
+    }
+
+    #[test]
+    fn f3_should_pause() {
+        Headless::reset();
+        let mut scene = gameplay();
+        Headless::tap_key(KeyboardKey::F3);
+        let state = scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(matches!(state, State::Next(_)), "got {:?}", state);
+    }
+
+    #[test]
+    fn f3_should_pause_a_finished_board() {
+        Headless::reset();
+        let mut scene = gameplay();
+        scene.board.set(0, 0, Cell::Yes).unwrap();
+        assert!(scene.board.is_done());
+        Headless::tap_key(KeyboardKey::Pause);
+        let state = scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(matches!(state, State::Keep), "got {:?}", state);
+        Headless::tap_key(KeyboardKey::F3);
+        let state = scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(matches!(state, State::Next(_)), "got {:?}", state);
+    }
+
+    #[test]
+    fn f2_should_mute_this_game_only() {
+        Headless::reset();
+        let mut scene = gameplay();
+        scene.on_setup(PlainConnector).unwrap();
+        assert!(!scene.mute);
+        Headless::tap_key(KeyboardKey::F2);
+        scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(scene.mute);
+        assert_eq!(PlainConnector.get_master_volume(), 1.0);
+    }
+
+    #[test]
+    fn f2_should_unmute_a_muted_start() {
+        Headless::reset();
+        PlainConnector.set_master_volume(0.0);
+        let mut scene = gameplay();
+        scene.on_setup(PlainConnector).unwrap();
+        assert!(scene.mute);
+        Headless::tap_key(KeyboardKey::F2);
+        scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(!scene.mute);
+        assert_eq!(PlainConnector.get_master_volume(), 1.0);
+    }
+
+    #[test]
+    fn escape_should_get_back() {
+        Headless::reset();
+        let mut scene = gameplay();
+        Headless::tap_key(KeyboardKey::Escape);
+        let state = scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(matches!(state, State::Prev(1)), "got {:?}", state);
+    }
+
+    #[test]
+    fn left_click_should_set_cell() {
+        Headless::reset();
+        let mut scene = gameplay();
+        scene
+            .on_resize(PlainConnector, Vector2 { x: 800.0, y: 600.0 })
+            .unwrap();
+        Headless::set_mouse_position(Vector2 {
+            x: scene.board_rect.x + scene.cell_size.x / 2.0,
+            y: scene.board_rect.y + scene.cell_size.y / 2.0,
+        });
+        Headless::click(MouseButton::Left);
+        scene.on_update(PlainConnector, 0.0).unwrap();
+        assert_eq!(scene.board.get(0, 0).unwrap(), Cell::Yes);
+        assert!(scene.board.is_done());
+    }
+
+    #[test]
+    fn ctrl_click_should_lock_cell() {
+        Headless::reset();
+        let mut scene = gameplay();
+        scene
+            .on_resize(PlainConnector, Vector2 { x: 800.0, y: 600.0 })
+            .unwrap();
+        Headless::set_mouse_position(Vector2 {
+            x: scene.board_rect.x + scene.cell_size.x / 2.0,
+            y: scene.board_rect.y + scene.cell_size.y / 2.0,
+        });
+        Headless::press_key(KeyboardKey::LeftControl);
+        Headless::click(MouseButton::Left);
+        scene.on_update(PlainConnector, 0.0).unwrap();
+        assert_eq!(scene.board.get(0, 0).unwrap(), Cell::No);
+    }
+
+    #[test]
+    fn time_should_stop_without_focus() {
+        Headless::reset();
+        let mut scene = gameplay();
+        scene.on_update(PlainConnector, 0.5).unwrap();
+        scene.on_focus_changed(PlainConnector, false).unwrap();
+        scene.on_update(PlainConnector, 0.5).unwrap();
+        assert_eq!(scene.time_lapse, 0.5);
+    }
+}
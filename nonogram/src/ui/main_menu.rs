@@ -1,6 +1,6 @@
 use super::gameplay::Gameplay;
-use crate::{game::board::BoardStruct, persist::Persist};
-use rscenes::{extras::XDGStore, prelude::*};
+use crate::{game::board::BoardStruct, persist::Geometry};
+use rscenes::prelude::*;
 
 const LB_5X5: usize = 0;
 const LB_10X10: usize = 1;
@@ -31,7 +31,7 @@ pub struct MainMenu {
 }
 
 impl Scene for MainMenu {
-    fn on_update(&mut self, rl: PlainConnector, _: f32) -> Result<State, String> {
+    fn on_update(&mut self, rl: PlainConnector, _: f32) -> Result<State, Error> {
         let font = rl.get_default_font();
         let screen_width = rl.get_render_width() as f32;
         let size = rl.measure_text_ex(font, "Nonogram", 84.0, 2.0);
@@ -78,24 +78,23 @@ impl Scene for MainMenu {
             }
         }
 
-        if KeyboardKey::F.is_released() {
+        if rl.is_key_released(KeyboardKey::F) {
             rl.toggle_fullscreen();
         }
 
-        if KeyboardKey::Escape.is_released() {
+        if rl.is_key_released(KeyboardKey::Escape) {
             Ok(State::Quit)
         } else {
             Ok(State::Keep)
         }
     }
 
-    fn on_exit(&mut self, rl: PlainConnector) -> Result<(), String> {
-        let geom: Persist = (
-            (self.geom.x as i32).max(800),
-            (self.geom.y as i32).max(600),
-            rl.is_window_fullscreen(),
-        );
-        if let Err(err) = XDGStore::save("nonogram", "window", geom) {
+    fn on_exit(&mut self, rl: PlainConnector) -> Result<(), Error> {
+        let geom = Geometry {
+            size: ((self.geom.x as i32).max(800), (self.geom.y as i32).max(600)),
+            fullscreen: rl.is_window_fullscreen(),
+        };
+        if let Err(err) = geom.save() {
             TraceLogLevel::Error.log(format!("error saving geometry: {:?}", err));
         }
         Ok(())
@@ -172,3 +171,62 @@ impl Default for MainMenu {
         }
     }
 }
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rscenes::extras::Headless;
+
+    fn center(rec: Rectangle) -> Vector2 {
+        Vector2 {
+            x: rec.x + rec.width / 2.0,
+            y: rec.y + rec.height / 2.0,
+        }
+    }
+
+    #[test]
+    fn it_should_highlight_hovered_button() {
+        Headless::reset();
+        let mut scene = MainMenu::default();
+        scene.on_update(PlainConnector, 0.0).unwrap();
+        Headless::set_mouse_position(center(scene.buttons[LB_10X10]));
+        scene.on_update(PlainConnector, 0.0).unwrap();
+        assert_eq!(scene.hover, [false, true, false, false]);
+    }
+
+    #[test]
+    fn clicking_a_size_should_start_a_game() {
+        Headless::reset();
+        let mut scene = MainMenu::default();
+        scene.on_update(PlainConnector, 0.0).unwrap();
+        Headless::set_mouse_position(center(scene.buttons[LB_5X5]));
+        Headless::click(MouseButton::Left);
+        let state = scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(matches!(state, State::Next(_)), "got {:?}", state);
+    }
+
+    #[test]
+    fn clicking_easy_should_toggle_it() {
+        Headless::reset();
+        let mut scene = MainMenu::default();
+        scene.on_update(PlainConnector, 0.0).unwrap();
+        Headless::set_mouse_position(center(scene.buttons[LB_EASY]));
+        Headless::click(MouseButton::Left);
+        let state = scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(matches!(state, State::Keep), "got {:?}", state);
+        assert!(scene.easy);
+    }
+
+    #[test]
+    fn escape_should_quit() {
+        Headless::reset();
+        let mut scene = MainMenu::default();
+        Headless::tap_key(KeyboardKey::Escape);
+        let state = scene.on_update(PlainConnector, 0.0).unwrap();
+        assert!(matches!(state, State::Quit), "got {:?}", state);
+    }
+}
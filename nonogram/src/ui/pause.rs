@@ -1,18 +1,24 @@
 use rscenes::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, Scene)]
 pub struct Pause;
 
-impl Scene for Pause {
-    fn on_update(&mut self, rl: PlainConnector, _: f32) -> Result<State, String> {
-        if KeyboardKey::F.is_released() {
+#[scene]
+impl Pause {
+    fn is_overlay(&self) -> bool {
+        true
+    }
+
+    #[update]
+    fn update(&mut self, rl: PlainConnector, _: f32) -> Result<State, Error> {
+        if rl.is_key_released(KeyboardKey::F) {
             rl.toggle_fullscreen();
         }
 
-        if KeyboardKey::F3.is_released() || KeyboardKey::Pause.is_released() {
+        if rl.is_key_released(KeyboardKey::F3) || rl.is_key_released(KeyboardKey::Pause) {
             Ok(State::Prev(1))
-        } else if KeyboardKey::Escape.is_released() {
-            Ok(State::Prev(2))
+        } else if rl.is_key_released(KeyboardKey::Escape) {
+            Ok(State::PopToRoot)
         } else {
             Ok(State::Keep)
         }
@@ -22,7 +28,7 @@ impl Scene for Pause {
     fn draw(&self, rl: Connector2D) {
         let font = rl.get_default_font();
         let screen = rl.get_render_rec();
-        rl.clear_background(Color::WHEAT);
+        rl.draw_rectangle_rec(screen, Color::WHEAT.fade(0.9));
 
         let text = "Nonogram";
         let size = rl.measure_text_ex(font, text, 84.0, 2.0);
@@ -0,0 +1,242 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote, Error, Expr, FnArg, Ident, ItemFn, Pat, PatType, ReturnType, Token, Type,
+};
+
+pub(crate) fn expand(
+    DrawArgs { tpe, camera }: DrawArgs,
+    mut input: ItemFn,
+) -> syn::Result<TokenStream2> {
+    if input.sig.receiver().is_none() {
+        return Err(Error::new_spanned(
+            &input.sig,
+            format!("`{}` must take `&self`", input.sig.ident),
+        ));
+    }
+    let con_name = find_connector(&tpe, &mut input)?;
+    let camera = camera.unwrap_or_else(|| tpe.get_default_camera());
+
+    let attrs = &input.attrs;
+    let args = &input.sig.inputs;
+    let body = &input.block;
+    let body = match &input.sig.output {
+        ReturnType::Default => quote! {
+            || -> ::core::result::Result<(), ::rscenes::Error> {
+                #body;
+                Ok(())
+            }
+        },
+        ReturnType::Type(_, ty) => quote! { || -> #ty #body },
+    };
+
+    let output = match tpe {
+        DrawType::Draw2D => quote! {
+
+            #(#attrs)*
+            fn draw_2d(#args) -> ::core::result::Result<(), ::rscenes::Error> {
+                let camera = #camera;
+                ::rscenes::prelude::Rcore::begin_mode_2d(&#con_name, camera);
+
+                let res = (#body)();
+
+                ::rscenes::prelude::Rcore::end_mode_2d(&#con_name);
+                res
+            }
+        },
+
+        DrawType::Draw3D => quote! {
+
+            #(#attrs)*
+            fn draw_3d(#args) -> ::core::result::Result<(), ::rscenes::Error> {
+                let camera = #camera;
+                ::rscenes::prelude::Rcore::begin_mode_3d(&#con_name, camera);
+
+                let res = (#body)();
+
+                ::rscenes::prelude::Rcore::end_mode_3d(&#con_name);
+                res
+            }
+        },
+
+        DrawType::DrawHUD => quote! {
+
+            #(#attrs)*
+            fn draw_hud(#args) -> ::core::result::Result<(), ::rscenes::Error> {
+                let camera = #camera;
+                ::rscenes::prelude::Rcore::begin_mode_2d(&#con_name, camera);
+
+                let res = (#body)();
+
+                ::rscenes::prelude::Rcore::end_mode_2d(&#con_name);
+                res
+            }
+        },
+    };
+
+    Ok(output)
+}
+
+/// Find the connector argument, by type name or as the only argument
+///
+/// Aliased connectors can't be told apart from any other type, they're taken when they're alone.
+fn find_connector(tpe: &DrawType, input: &mut ItemFn) -> syn::Result<Ident> {
+    let (expected, other) = tpe.get_connector_names();
+    let typed: Vec<&mut PatType> = input
+        .sig
+        .inputs
+        .iter_mut()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(arg) => Some(arg),
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    let names: Vec<Option<String>> = typed.iter().map(|arg| type_name(&arg.ty)).collect();
+
+    let index = match names
+        .iter()
+        .position(|name| name.as_deref() == Some(expected))
+    {
+        Some(index) => Some(index),
+        None => {
+            if let Some(index) = names.iter().position(|name| name.as_deref() == Some(other)) {
+                return Err(Error::new_spanned(
+                    &typed[index].ty,
+                    format!(
+                        "#[draw({})] expects a `{}`, found `{}`",
+                        tpe.get_name(),
+                        expected,
+                        other
+                    ),
+                ));
+            }
+            (typed.len() == 1).then_some(0)
+        }
+    };
+    let arg = match index.and_then(|index| typed.into_iter().nth(index)) {
+        Some(arg) => arg,
+        None => {
+            return Err(Error::new_spanned(
+                &input.sig,
+                format!("`{}` must take a `{}` argument", input.sig.ident, expected),
+            ))
+        }
+    };
+
+    match &*arg.pat {
+        Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => Ok(pat.ident.clone()),
+        Pat::Wild(_) => {
+            let ident = format_ident!("connector");
+            *arg.pat = parse_quote!(#ident);
+            Ok(ident)
+        }
+        pat => Err(Error::new_spanned(
+            pat,
+            "the connector argument must be bound to a name",
+        )),
+    }
+}
+
+/// Last segment of a type path, `Connector2D` for `rscenes::prelude::Connector2D`
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(ty) => ty.path.segments.last().map(|seg| seg.ident.to_string()),
+        Type::Group(ty) => type_name(&ty.elem),
+        Type::Paren(ty) => type_name(&ty.elem),
+        _ => None,
+    }
+}
+
+/// `shapes | models | hud`, optionally followed by `camera = <expr>`
+pub(crate) struct DrawArgs {
+    pub(crate) tpe: DrawType,
+    camera: Option<Expr>,
+}
+
+impl Parse for DrawArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Err(input.error("expected one of: `shapes`, `models`, `hud`"));
+        }
+        let tpe: Ident = input.parse()?;
+        let tpe = DrawType::try_from(&tpe)?;
+        let mut camera = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "camera" {
+                return Err(Error::new_spanned(
+                    &key,
+                    format!("unexpected argument `{}`, expected `camera = <expr>`", key),
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            camera = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the draw arguments"));
+        }
+        Ok(Self { tpe, camera })
+    }
+}
+
+pub(crate) enum DrawType {
+    Draw2D,
+    Draw3D,
+    DrawHUD,
+}
+
+impl DrawType {
+    /// The `Scene` method the drawing function becomes
+    pub(crate) fn get_method_name(&self) -> &'static str {
+        match self {
+            DrawType::Draw2D => "draw_2d",
+            DrawType::Draw3D => "draw_3d",
+            DrawType::DrawHUD => "draw_hud",
+        }
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            DrawType::Draw2D => "shapes",
+            DrawType::Draw3D => "models",
+            DrawType::DrawHUD => "hud",
+        }
+    }
+
+    /// The connector taken, and the other one
+    fn get_connector_names(&self) -> (&'static str, &'static str) {
+        match self {
+            DrawType::Draw3D => ("Connector3D", "Connector2D"),
+            _ => ("Connector2D", "Connector3D"),
+        }
+    }
+
+    fn get_default_camera(&self) -> Expr {
+        match self {
+            DrawType::Draw2D => parse_quote!(self.get_camera_2d()),
+            DrawType::Draw3D => parse_quote!(self.get_camera_3d()),
+            DrawType::DrawHUD => parse_quote!(self.get_camera_hud()),
+        }
+    }
+}
+
+impl TryFrom<&Ident> for DrawType {
+    type Error = Error;
+
+    fn try_from(value: &Ident) -> syn::Result<Self> {
+        match value.to_string().as_str() {
+            "shapes" => Ok(DrawType::Draw2D),
+            "models" => Ok(DrawType::Draw3D),
+            "hud" => Ok(DrawType::DrawHUD),
+            _ => Err(Error::new_spanned(
+                value,
+                format!(
+                    "unexpected attribute: draw({}), valid attributes: shapes | models | hud",
+                    value
+                ),
+            )),
+        }
+    }
+}
@@ -1,125 +1,64 @@
 extern crate proc_macro;
 
-use proc_macro::{Span, TokenStream, TokenTree};
-use quote::quote;
-use syn::{parse_macro_input, parse_quote, FnArg, Ident, ItemFn, Pat, Type};
+mod draw;
+mod scene;
+
+use draw::DrawArgs;
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, Error, ItemFn, ItemImpl};
 
 /// Use #[draw(shapes)] to draw 2D shapes, #[draw(models)] to draw 3D models, and #[draw(hud)] to
 /// draw HUD.
+///
+/// Shapes and models are drawn through the scene's `get_camera_2d()` and `get_camera_3d()`, and
+/// the HUD through `get_camera_hud()`, unaffected by the world camera. Give another camera with
+/// `#[draw(hud, camera = self.ui_camera())]`.
+///
+/// The function takes `&self` and the connector, `Connector2D` for shapes and HUD, `Connector3D`
+/// for models, under any path or alias. Its body either returns nothing or a
+/// `Result<(), rscenes::Error>`.
 #[proc_macro_attribute]
 pub fn draw(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input: ItemFn = parse_macro_input!(item as ItemFn);
-    let original = input.block.stmts;
-    let mut args: Vec<FnArg> = vec![];
-    let mut con_name: Option<String> = None;
-
-    let attr: DrawType = match attr.into_iter().next() {
-        Some(TokenTree::Ident(attr)) => attr.to_string().into(),
-        Some(attr) => panic!("unexpected attribute {:?}", attr),
-        None => panic!("draw macro expects one attribute: shades | models | hud"),
-    };
-
-    for arg in &input.sig.inputs {
-        args.push(arg.clone());
-        if let FnArg::Typed(tpe) = arg {
-            if let Type::Path(ty) = &*tpe.ty {
-                if let Type::Path(con_tpe) = &attr.get_connection_tpe() {
-                    // Naïve type comparison approach
-                    if con_tpe.path.segments.last().unwrap().ident
-                        == ty.path.segments.last().unwrap().ident
-                    {
-                        if let Pat::Ident(ident) = &*tpe.pat {
-                            con_name = Some(ident.ident.to_string());
-                        }
-                    }
-                }
-            }
-        }
-    }
-    let con_name = Ident::new(
-        &con_name
-            .unwrap_or_else(|| panic!("connection not found in fn {} arguments", input.sig.ident)),
-        Span::call_site().into(),
-    );
-
-    let output = match attr {
-        DrawType::Draw2D => quote! {
-
-            fn draw_2d(#(#args),*) -> Result<(), String> {
-                let camera = self.get_camera_2d();
-                #con_name.begin_mode_2d(camera);
-
-                let res = || -> Result<(), String> {
-                    #(#original)*
-                    Ok(())
-                }();
-
-                #con_name.end_mode_2d();
-                res
-            }
-        },
-
-        DrawType::Draw3D => quote! {
-
-            fn draw_3d(#(#args),*) -> Result<(), String> {
-                let camera = self.get_camera_3d();
-                #con_name.begin_mode_3d(camera);
-
-                let res = || -> Result<(), String> {
-                    #(#original)*
-                    Ok(())
-                }();
-
-                #con_name.end_mode_3d();
-                res
-            }
-        },
-
-        DrawType::DrawHUD => quote! {
-
-            fn draw_hud(#(#args),*) -> Result<(), String> {
-                let camera = self.get_camera_2d();
-                #con_name.begin_mode_2d(camera);
-
-                let res = || -> Result<(), String> {
-                    #(#original)*
-                    Ok(())
-                }();
-
-                #con_name.end_mode_2d();
-                res
-            }
-        },
-    };
-
-    output.into()
-}
-
-enum DrawType {
-    Draw2D,
-    Draw3D,
-    DrawHUD,
+    let args = parse_macro_input!(attr as DrawArgs);
+    let input = parse_macro_input!(item as ItemFn);
+    draw::expand(args, input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
 }
 
-impl DrawType {
-    fn get_connection_tpe(&self) -> Type {
-        match self {
-            DrawType::Draw3D => parse_quote!(rscene::prelude::Connector3D),
-            _ => parse_quote!(rscene::prelude::Connector2D),
-        }
-    }
+/// Derive the scene fields #[scene] relies on, `Scene` still requires `#[derive(Debug)]`.
+///
+/// - `#[camera2d]` and `#[camera3d]` tag the fields returned by `get_camera_2d()` and
+///   `get_camera_3d()`.
+/// - `#[assets]` tags the `AssetCache` returned by `assets()`, for hot reload.
+/// - `#[asset(texture = "path")]` tags an `Option<Asset<_>>` loaded through that cache on
+///   `on_setup()`, and unloaded on `on_teardown()`. Kinds are `texture`, `sound`, `font`,
+///   `music`, and `model`.
+///
+/// Assets are kept while the scene is paused or covered by an overlay, until it's dropped from
+/// the stack.
+#[proc_macro_derive(Scene, attributes(camera2d, camera3d, assets, asset))]
+pub fn derive_scene(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    scene::derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
 }
 
-impl From<String> for DrawType {
-    fn from(value: String) -> Self {
-        match value.as_str() {
-            "shapes" => DrawType::Draw2D,
-            "models" => DrawType::Draw3D,
-            "hud" => DrawType::DrawHUD,
-            value => panic!(
-                "unexpected attribute: draw({}), valid attributes: shapes | models | hud",
-                value
-            ),
-        }
-    }
+/// Implement `Scene` from the methods of an `impl` block, for a #[derive(Scene)] struct.
+///
+/// - `#[update]` and `#[fixed_update]` tag the methods run as `on_update()` and
+///   `on_fixed_update()`.
+/// - `#[draw(shapes)]`, `#[draw(models)]`, and `#[draw(hud)]` tag the drawing methods, as the
+///   #[draw] macro does.
+/// - Methods named after `Scene` methods, like `on_resume()` or `is_overlay()`, implement them,
+///   `on_setup()`, `on_load()`, and `on_teardown()` running along with the asset handling.
+///
+/// Any other item is kept in the inherent `impl`.
+#[proc_macro_attribute]
+pub fn scene(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemImpl);
+    scene::expand(attr.into(), input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
 }
@@ -0,0 +1,409 @@
+use crate::draw::{self, DrawArgs};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+use syn::{
+    parse::{Parse, ParseStream},
+    Attribute, Data, DataStruct, DeriveInput, Error, Expr, Field, Fields, Ident, ImplItem,
+    ImplItemFn, ItemFn, ItemImpl, Token, Visibility,
+};
+
+/// `Scene` methods #[scene] takes from the `impl` block when they're defined there
+const SCENE_METHODS: &[&str] = &[
+    "name",
+    "is_overlay",
+    "get_camera_2d",
+    "get_camera_3d",
+    "get_camera_hud",
+    "on_setup",
+    "on_load",
+    "on_resume",
+    "on_pause",
+    "on_resize",
+    "on_focus_changed",
+    "tweens",
+    "scheduler",
+    "assets",
+    "on_assets_reloaded",
+    "take_payload",
+    "on_exit",
+    "on_teardown",
+    "draw_2d",
+    "draw_3d",
+    "draw_hud",
+    "on_fixed_update",
+    "on_update",
+];
+
+/// `AssetCache` loaders taking a single path
+const ASSET_KINDS: &[&str] = &["texture", "sound", "font", "music", "model"];
+
+pub(crate) fn derive(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => fields.named.iter().collect(),
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
+        }) => vec![],
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "#[derive(Scene)] only supports unit structs and structs with named fields",
+            ))
+        }
+    };
+
+    let mut camera_2d = None;
+    let mut camera_3d = None;
+    let mut cache = None;
+    let mut assets = vec![];
+    for field in fields {
+        let name = field.ident.as_ref().expect("named field");
+        for attr in field.attrs.iter() {
+            if attr.path().is_ident("camera2d") {
+                set_field(&mut camera_2d, name, attr)?;
+            } else if attr.path().is_ident("camera3d") {
+                set_field(&mut camera_3d, name, attr)?;
+            } else if attr.path().is_ident("assets") {
+                set_field(&mut cache, name, attr)?;
+            } else if attr.path().is_ident("asset") {
+                assets.push((name, attr.parse_args::<AssetArgs>()?, attr));
+            }
+        }
+    }
+
+    let camera_2d = camera_2d.map(|field| {
+        quote! {
+            fn scene_camera_2d(&self) -> ::rscenes::prelude::Camera2D {
+                self.#field
+            }
+        }
+    });
+    let camera_3d = camera_3d.map(|field| {
+        quote! {
+            fn scene_camera_3d(&self) -> ::rscenes::prelude::Camera3D {
+                self.#field
+            }
+        }
+    });
+    let cache_methods = match (cache, assets.first()) {
+        (None, None) => None,
+        (None, Some((_, _, attr))) => {
+            return Err(Error::new_spanned(
+                attr,
+                "#[asset] fields are loaded through an #[assets] `AssetCache` field, found none",
+            ))
+        }
+        (Some(cache), _) => {
+            let asset_methods = (!assets.is_empty()).then(|| {
+                let fields: Vec<&Ident> = assets.iter().map(|(field, _, _)| *field).collect();
+                let kinds = assets.iter().map(|(_, args, _)| &args.kind);
+                let paths = assets.iter().map(|(_, args, _)| &args.path);
+                quote! {
+                    fn scene_load_assets(&mut self) -> ::core::result::Result<(), ::rscenes::Error> {
+                        #(
+                            if self.#fields.is_none() {
+                                self.#fields = ::core::option::Option::Some(self.#cache.#kinds(#paths)?);
+                            }
+                        )*
+                        Ok(())
+                    }
+
+                    fn scene_unload_assets(&mut self) {
+                        #(self.#fields = ::core::option::Option::None;)*
+                        self.#cache.purge();
+                    }
+                }
+            });
+            Some(quote! {
+                fn scene_assets(&mut self) -> ::core::option::Option<&mut ::rscenes::prelude::AssetCache> {
+                    ::core::option::Option::Some(&mut self.#cache)
+                }
+
+                #asset_methods
+            })
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rscenes::SceneFields for #name #ty_generics #where_clause {
+            #camera_2d
+            #camera_3d
+            #cache_methods
+        }
+    })
+}
+
+/// Take the field tagged with `attr`, which can only tag one
+fn set_field<'a>(
+    slot: &mut Option<&'a Ident>,
+    name: &'a Ident,
+    attr: &Attribute,
+) -> syn::Result<()> {
+    attr.meta.require_path_only()?;
+    if let Some(first) = slot {
+        let tag = attr.path().get_ident().expect("tag name");
+        return Err(Error::new_spanned(
+            attr,
+            format!("#[{}] is already given to `{}`", tag, first),
+        ));
+    }
+    *slot = Some(name);
+    Ok(())
+}
+
+pub(crate) fn expand(attr: TokenStream2, mut input: ItemImpl) -> syn::Result<TokenStream2> {
+    if !attr.is_empty() {
+        return Err(Error::new_spanned(attr, "#[scene] takes no arguments"));
+    }
+    if let Some((_, path, _)) = &input.trait_ {
+        return Err(Error::new_spanned(
+            path,
+            "#[scene] goes on an inherent `impl` block, it implements `Scene` itself",
+        ));
+    }
+
+    let mut inherent = vec![];
+    let mut methods = BTreeMap::<String, (Ident, TokenStream2)>::new();
+    let mut wrapped = BTreeMap::<String, ImplItemFn>::new();
+    for item in input.items.drain(..) {
+        let mut method = match item {
+            ImplItem::Fn(method) => method,
+            item => {
+                inherent.push(item);
+                continue;
+            }
+        };
+        let handler = match take_handler(&mut method.attrs)? {
+            Some(handler) => handler,
+            None if SCENE_METHODS.contains(&method.sig.ident.to_string().as_str()) => {
+                Handler::Scene
+            }
+            None => {
+                inherent.push(ImplItem::Fn(method));
+                continue;
+            }
+        };
+        let original = method.sig.ident.clone();
+        method.vis = Visibility::Inherited;
+        let name = match &handler {
+            Handler::Update => "on_update".to_owned(),
+            Handler::FixedUpdate => "on_fixed_update".to_owned(),
+            Handler::Draw(args) => args.tpe.get_method_name().to_owned(),
+            Handler::Scene => original.to_string(),
+        };
+        if let Some((first, _)) = methods.get(&name) {
+            return Err(Error::new_spanned(
+                &original,
+                format!("`{}` is already implemented by `{}`", name, first),
+            ));
+        }
+        let tokens = match handler {
+            Handler::Update | Handler::FixedUpdate => {
+                method.sig.ident = format_ident!("{}", name, span = original.span());
+                quote! { #method }
+            }
+            Handler::Draw(args) => {
+                let input = ItemFn {
+                    attrs: method.attrs,
+                    vis: method.vis,
+                    sig: method.sig,
+                    block: Box::new(method.block),
+                };
+                draw::expand(args, input)?
+            }
+            Handler::Scene if ["on_setup", "on_load", "on_teardown"].contains(&name.as_str()) => {
+                wrapped.insert(name.clone(), method);
+                TokenStream2::new()
+            }
+            Handler::Scene => quote! { #method },
+        };
+        methods.insert(name, (original, tokens));
+    }
+
+    let mut scene_methods = default_methods(&methods);
+    scene_methods.extend(methods.into_values().map(|(_, tokens)| tokens));
+    for (name, method) in wrapped {
+        scene_methods.push(wrap(&name, method));
+    }
+
+    input.items = inherent;
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+    let self_ty = &input.self_ty;
+    Ok(quote! {
+        #input
+
+        impl #impl_generics ::rscenes::prelude::Scene for #self_ty #where_clause {
+            #(#scene_methods)*
+        }
+    })
+}
+
+/// Methods delegated to the #[derive(Scene)] fields when the `impl` block doesn't define them
+fn default_methods<T>(defined: &BTreeMap<String, T>) -> Vec<TokenStream2> {
+    let defaults = [
+        (
+            "get_camera_2d",
+            quote! {
+                fn get_camera_2d(&self) -> ::rscenes::prelude::Camera2D {
+                    ::rscenes::SceneFields::scene_camera_2d(self)
+                }
+            },
+        ),
+        (
+            "get_camera_3d",
+            quote! {
+                fn get_camera_3d(&self) -> ::rscenes::prelude::Camera3D {
+                    ::rscenes::SceneFields::scene_camera_3d(self)
+                }
+            },
+        ),
+        (
+            "assets",
+            quote! {
+                fn assets(&mut self) -> ::core::option::Option<&mut ::rscenes::prelude::AssetCache> {
+                    ::rscenes::SceneFields::scene_assets(self)
+                }
+            },
+        ),
+        (
+            "on_setup",
+            quote! {
+                fn on_setup(
+                    &mut self,
+                    _: ::rscenes::prelude::PlainConnector,
+                ) -> ::core::result::Result<(), ::rscenes::Error> {
+                    ::rscenes::SceneFields::scene_load_assets(self)
+                }
+            },
+        ),
+        (
+            "on_load",
+            quote! {
+                fn on_load(
+                    &mut self,
+                    _: ::rscenes::prelude::PlainConnector,
+                ) -> ::core::result::Result<(), ::rscenes::Error> {
+                    ::rscenes::SceneFields::scene_load_assets(self)
+                }
+            },
+        ),
+        (
+            "on_teardown",
+            quote! {
+                fn on_teardown(
+                    &mut self,
+                    _: ::rscenes::prelude::PlainConnector,
+                ) -> ::core::result::Result<(), ::rscenes::Error> {
+                    ::rscenes::SceneFields::scene_unload_assets(self);
+                    Ok(())
+                }
+            },
+        ),
+    ];
+    defaults
+        .into_iter()
+        .filter(|(name, _)| !defined.contains_key(*name))
+        .map(|(_, tokens)| tokens)
+        .collect()
+}
+
+/// Run the asset handling along with `on_setup()`, `on_load()`, or `on_teardown()`
+fn wrap(name: &str, method: ImplItemFn) -> TokenStream2 {
+    let ImplItemFn {
+        attrs, sig, block, ..
+    } = method;
+    let output = &sig.output;
+    if name == "on_teardown" {
+        quote! {
+            #(#attrs)*
+            #sig {
+                let res = (|| #output #block)();
+                ::rscenes::SceneFields::scene_unload_assets(self);
+                res
+            }
+        }
+    } else {
+        quote! {
+            #(#attrs)*
+            #sig {
+                ::rscenes::SceneFields::scene_load_assets(self)?;
+                #block
+            }
+        }
+    }
+}
+
+enum Handler {
+    Update,
+    FixedUpdate,
+    Draw(DrawArgs),
+    /// Method named after a `Scene` method
+    Scene,
+}
+
+/// Take the #[update], #[fixed_update], or #[draw] tag off a method
+fn take_handler(attrs: &mut Vec<Attribute>) -> syn::Result<Option<Handler>> {
+    let mut handler: Option<(Handler, Attribute)> = None;
+    let mut kept = vec![];
+    for attr in attrs.drain(..) {
+        let tag = if attr.path().is_ident("update") {
+            attr.meta.require_path_only()?;
+            Handler::Update
+        } else if attr.path().is_ident("fixed_update") {
+            attr.meta.require_path_only()?;
+            Handler::FixedUpdate
+        } else if attr.path().is_ident("draw") {
+            Handler::Draw(attr.parse_args()?)
+        } else {
+            kept.push(attr);
+            continue;
+        };
+        if let Some((_, first)) = &handler {
+            return Err(Error::new_spanned(
+                &attr,
+                format!(
+                    "a method handles a single callback, it's already tagged #[{}]",
+                    first.path().get_ident().expect("tag name")
+                ),
+            ));
+        }
+        handler = Some((tag, attr));
+    }
+    *attrs = kept;
+    Ok(handler.map(|(handler, _)| handler))
+}
+
+/// `texture = "path"`, the path being any expression
+struct AssetArgs {
+    kind: Ident,
+    path: Expr,
+}
+
+impl Parse for AssetArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kind: Ident = input.parse()?;
+        if !ASSET_KINDS.contains(&kind.to_string().as_str()) {
+            return Err(Error::new_spanned(
+                &kind,
+                format!(
+                    "unexpected asset kind `{}`, valid kinds: {}",
+                    kind,
+                    ASSET_KINDS.join(" | ")
+                ),
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        let path = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the asset path"));
+        }
+        Ok(Self { kind, path })
+    }
+}
@@ -0,0 +1,9 @@
+use rscenes::prelude::*;
+
+#[derive(Debug, Scene)]
+struct Gameplay {
+    #[asset(texture = "assets/ball.png")]
+    ball: Option<Asset<Texture2D>>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rscenes::prelude::*;
+
+#[derive(Debug, Scene)]
+struct Gameplay {
+    #[camera2d]
+    world: Camera2D,
+    #[camera2d]
+    minimap: Camera2D,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use rscenes::prelude::*;
+
+#[derive(Debug, Scene)]
+struct Gameplay {}
+
+#[scene]
+impl Gameplay {
+    #[update]
+    fn tick(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+        Ok(State::Keep)
+    }
+
+    fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+        Ok(State::Quit)
+    }
+}
+
+fn main() {}
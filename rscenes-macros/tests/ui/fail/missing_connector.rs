@@ -0,0 +1,11 @@
+use rscenes::prelude::*;
+
+#[derive(Debug)]
+struct Missing;
+
+impl Scene for Missing {
+    #[draw(shapes)]
+    fn draw(&self) {}
+}
+
+fn main() {}
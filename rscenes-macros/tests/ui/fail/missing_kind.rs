@@ -0,0 +1,11 @@
+use rscenes::prelude::*;
+
+#[derive(Debug)]
+struct Missing;
+
+impl Scene for Missing {
+    #[draw]
+    fn draw(&self, rl: Connector2D) {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rscenes::prelude::*;
+
+#[derive(Debug)]
+struct Missing;
+
+impl Scene for Missing {
+    #[draw(shapes)]
+    fn draw(rl: Connector2D) {}
+}
+
+fn main() {}
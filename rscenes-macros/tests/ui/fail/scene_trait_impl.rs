@@ -0,0 +1,9 @@
+use rscenes::prelude::*;
+
+#[derive(Debug, Scene)]
+struct Gameplay {}
+
+#[scene]
+impl Scene for Gameplay {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rscenes::prelude::*;
+
+#[derive(Debug)]
+struct Unknown;
+
+impl Scene for Unknown {
+    #[draw(hud, zoom = 2.0)]
+    fn draw(&self, rl: Connector2D) {}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rscenes::prelude::*;
+
+#[derive(Debug, Scene)]
+struct Gameplay {
+    #[assets]
+    cache: AssetCache,
+    #[asset(picture = "assets/ball.png")]
+    ball: Option<Asset<Texture2D>>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rscenes::prelude::*;
+
+#[derive(Debug)]
+struct Wrong;
+
+impl Scene for Wrong {
+    #[draw(hud)]
+    fn draw(&self, rl: Connector3D) {}
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use rscenes::prelude::{draw, Color, ColorExt, Connector2D as Screen, Rtext, Scene};
+
+#[derive(Debug)]
+struct Aliased;
+
+impl Scene for Aliased {
+    #[draw(hud)]
+    fn hud(&self, screen: Screen) {
+        screen.draw_text("score", 10, 10, 20, Color::BLACK);
+    }
+}
+
+fn main() {}
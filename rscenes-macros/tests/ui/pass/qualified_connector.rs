@@ -0,0 +1,16 @@
+use rscenes::prelude::{draw, Color, ColorExt, Rcore, Scene};
+
+#[derive(Debug)]
+struct Qualified;
+
+impl Scene for Qualified {
+    #[draw(shapes)]
+    fn draw(&self, rl: ::rscenes::prelude::Connector2D) {
+        rl.clear_background(Color::WHITE);
+    }
+
+    #[draw(models)]
+    fn models(&self, _: rscenes::prelude::Connector3D) {}
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use rscenes::prelude::*;
+
+#[derive(Debug)]
+struct Fallible;
+
+impl Fallible {
+    fn check(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl Scene for Fallible {
+    #[draw(shapes)]
+    fn draw(&self, rl: Connector2D) -> Result<(), Error> {
+        self.check()?;
+        rl.draw_fps(0, 0);
+        Ok(())
+    }
+
+    #[draw(hud, camera = Camera2D::empty())]
+    fn hud(&self, _rl: Connector2D) -> Result<(), Error> {
+        self.check()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,88 @@
+use rscenes::prelude::*;
+
+#[derive(Debug, Scene)]
+struct Gameplay {
+    #[camera2d]
+    camera: Camera2D,
+    #[assets]
+    cache: AssetCache,
+    #[asset(texture = "assets/ball.png")]
+    ball: Option<Asset<Texture2D>>,
+    #[asset(sound = format!("assets/{}.ogg", "bounce"))]
+    bounce: Option<Asset<Sound>>,
+    score: u32,
+    torn_down: bool,
+}
+
+#[scene]
+impl Gameplay {
+    const GOAL: u32 = 10;
+
+    fn name(&self) -> Option<&str> {
+        Some("gameplay")
+    }
+
+    #[update]
+    fn tick(&mut self, rl: PlainConnector, _: f32) -> Result<State, Error> {
+        if rl.is_key_released(KeyboardKey::Space) {
+            self.score += 1;
+        }
+        Ok(if self.won() { State::Quit } else { State::Keep })
+    }
+
+    #[draw(shapes)]
+    fn world(&self, rl: Connector2D) {
+        if let Some(ball) = &self.ball {
+            rl.draw_texture(ball.get(), 0, 0, Color::WHITE);
+        }
+    }
+
+    #[draw(hud)]
+    fn hud(&self, rl: Connector2D) -> Result<(), Error> {
+        rl.draw_text(format!("{}", self.score), 8, 8, 20, Color::BLACK);
+        Ok(())
+    }
+
+    fn on_teardown(&mut self, _: PlainConnector) -> Result<(), Error> {
+        self.torn_down = true;
+        Ok(())
+    }
+
+    pub fn won(&self) -> bool {
+        self.score >= Self::GOAL
+    }
+}
+
+#[derive(Debug, Scene)]
+struct Empty {}
+
+#[scene]
+impl Empty {}
+
+fn main() {
+    let camera = Camera2D {
+        zoom: 2.0,
+        ..Camera2D::empty()
+    };
+    let mut scene = Gameplay {
+        camera,
+        cache: AssetCache::new(),
+        ball: None,
+        bounce: None,
+        score: 0,
+        torn_down: false,
+    };
+    assert_eq!(scene.name(), Some("gameplay"));
+    assert_eq!(scene.get_camera_2d().zoom, 2.0);
+    assert_eq!(scene.get_camera_3d().fovy, Camera3D::empty().fovy);
+    assert!(scene.assets().is_some());
+    assert!(!scene.won());
+    scene.on_teardown(PlainConnector).unwrap();
+    assert!(scene.torn_down);
+    assert!(scene.ball.is_none() && scene.bounce.is_none());
+
+    let mut empty = Empty {};
+    assert!(empty.assets().is_none());
+    assert!(!empty.is_overlay());
+    empty.on_setup(PlainConnector).unwrap();
+}
@@ -0,0 +1,50 @@
+use std::{error::Error, ffi::IntoStringError, fmt, str::Utf8Error};
+
+/// Errors raised by the Raylib connector
+#[derive(Debug)]
+pub enum RaylibError {
+    /// Raylib failed to load an asset
+    Load(String),
+    /// Requested file does not exist
+    FileNotFound(String),
+    /// Raylib returned an invalid value
+    Invalid(String),
+    /// Raylib returned a non UTF-8 string
+    Utf8(Utf8Error),
+    /// Raylib returned a malformed C string
+    CString(IntoStringError),
+}
+
+impl fmt::Display for RaylibError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Load(msg) => write!(f, "{msg}"),
+            Self::FileNotFound(filename) => write!(f, "file {filename} not found"),
+            Self::Invalid(msg) => write!(f, "{msg}"),
+            Self::Utf8(_) => write!(f, "invalid UTF-8 string"),
+            Self::CString(_) => write!(f, "invalid C string"),
+        }
+    }
+}
+
+impl Error for RaylibError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Utf8(err) => Some(err),
+            Self::CString(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<Utf8Error> for RaylibError {
+    fn from(value: Utf8Error) -> Self {
+        Self::Utf8(value)
+    }
+}
+
+impl From<IntoStringError> for RaylibError {
+    fn from(value: IntoStringError) -> Self {
+        Self::CString(value)
+    }
+}
@@ -1,9 +1,10 @@
+use crate::error::RaylibError;
 use crate::raudio::RaudioImpl;
 use raylib_ffi::*;
 
 pub trait AudioStreamExt: Sized {
     /// Load audio stream (to stream raw audio pcm data)
-    fn load(sample_rate: u32, sample_size: u32, channels: u32) -> Result<Self, String>;
+    fn load(sample_rate: u32, sample_size: u32, channels: u32) -> Result<Self, RaylibError>;
     /// Default size for new audio streams
     fn set_default_buffer_size(size: i32);
 
@@ -34,7 +35,7 @@ pub trait AudioStreamExt: Sized {
 }
 
 impl AudioStreamExt for AudioStream {
-    fn load(sample_rate: u32, sample_size: u32, channels: u32) -> Result<Self, String> {
+    fn load(sample_rate: u32, sample_size: u32, channels: u32) -> Result<Self, RaylibError> {
         RaudioImpl::__load_audio_stream(sample_rate, sample_size, channels)
     }
 
@@ -1,3 +1,4 @@
+use crate::error::RaylibError;
 use crate::rtext::RtextImpl;
 use std::{fmt::Display, marker::PhantomData};
 
@@ -10,7 +11,7 @@ pub struct Codepoints {
 
 impl Codepoints {
     /// Load all codepoints from a UTF-8 text string, codepoints count returned by parameter
-    pub fn load(text: impl Display) -> Result<Self, String> {
+    pub fn load(text: impl Display) -> Result<Self, RaylibError> {
         RtextImpl::__load_codepoints(text)
     }
 
@@ -39,12 +40,12 @@ impl Codepoints {
     }
 
     /// Get a codepoint from a specific index
-    pub fn get(&self, index: usize) -> Result<i32, String> {
+    pub fn get(&self, index: usize) -> Result<i32, RaylibError> {
         if self.inner.is_null() {
-            return Err("null codepoints".to_owned());
+            return Err(RaylibError::Invalid("null codepoints".to_owned()));
         }
         if index >= self.count {
-            return Err("index overflow".to_owned());
+            return Err(RaylibError::Invalid("index overflow".to_owned()));
         }
 
         unsafe { Ok(*self.inner.add(index)) }
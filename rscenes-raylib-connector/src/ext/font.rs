@@ -1,3 +1,4 @@
+use crate::error::RaylibError;
 use crate::{assets::Codepoints, rtext::RtextImpl};
 use raylib_ffi::*;
 use std::fmt::Display;
@@ -6,11 +7,15 @@ pub trait FontExt: Sized {
     /// Get the default Font
     fn default() -> Self;
     /// Load font from file into GPU memory (VRAM)
-    fn load(filename: impl Display) -> Result<Self, String>;
+    fn load(filename: impl Display) -> Result<Self, RaylibError>;
     /// Load font from memory buffer, fileType refers to extension: i.e. '.ttf'
-    fn load_from_memory(tpe: impl Display, data: &[u8], font_size: i32) -> Result<Self, String>;
+    fn load_from_memory(
+        tpe: impl Display,
+        data: &[u8],
+        font_size: i32,
+    ) -> Result<Self, RaylibError>;
     /// Load font from Image (XNA style)
-    fn load_from_image(image: Image, key: Color, first_char: i32) -> Result<Self, String>;
+    fn load_from_image(image: Image, key: Color, first_char: i32) -> Result<Self, RaylibError>;
     /// Check whether a font is ready
     fn is_ready(self) -> bool;
     /// Unload font from GPU memory (VRAM)
@@ -24,15 +29,19 @@ impl FontExt for Font {
         RtextImpl::__get_default_font()
     }
 
-    fn load(filename: impl Display) -> Result<Self, String> {
+    fn load(filename: impl Display) -> Result<Self, RaylibError> {
         RtextImpl::__load_font(filename)
     }
 
-    fn load_from_memory(tpe: impl Display, data: &[u8], font_size: i32) -> Result<Self, String> {
+    fn load_from_memory(
+        tpe: impl Display,
+        data: &[u8],
+        font_size: i32,
+    ) -> Result<Self, RaylibError> {
         RtextImpl::__load_font_from_memory(tpe, data, font_size, Codepoints::default())
     }
 
-    fn load_from_image(image: Image, key: Color, first_char: i32) -> Result<Self, String> {
+    fn load_from_image(image: Image, key: Color, first_char: i32) -> Result<Self, RaylibError> {
         RtextImpl::__load_font_from_image(image, key, first_char)
     }
 
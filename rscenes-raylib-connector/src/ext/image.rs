@@ -1,10 +1,11 @@
+use crate::error::RaylibError;
 use crate::rtextures::RtexturesImpl;
 use raylib_ffi::{enums::PixelFormat, *};
 use std::fmt::Display;
 
 pub trait ImageExt: Sized {
     /// Load image from file into CPU memory (RAM)
-    fn load(filename: impl Display) -> Result<Self, String>;
+    fn load(filename: impl Display) -> Result<Self, RaylibError>;
     /// Load image from RAW file data
     fn load_raw(
         filename: impl Display,
@@ -12,13 +13,17 @@ pub trait ImageExt: Sized {
         height: i32,
         format: impl Into<usize>,
         header_size: i32,
-    ) -> Result<Self, String>;
+    ) -> Result<Self, RaylibError>;
     /// Load image from SVG file data or string with specified size
-    fn load_svg(filename_or_string: impl Display, width: i32, height: i32) -> Result<Self, String>;
+    fn load_svg(
+        filename_or_string: impl Display,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, RaylibError>;
     /// Load image sequence from file (frames appended to image.data)
-    fn load_anim(filename: impl Display) -> Result<(Self, i32), String>;
+    fn load_anim(filename: impl Display) -> Result<(Self, i32), RaylibError>;
     /// Load image from memory buffer, fileType refers to extension: i.e. '.png'
-    fn load_from_memory(tpe: ImageType, data: &[u8]) -> Result<Self, String>;
+    fn load_from_memory(tpe: ImageType, data: &[u8]) -> Result<Self, RaylibError>;
     /// Load image from GPU texture data
     fn load_from_texture(texture: Texture2D) -> Self;
     /// Load image from screen buffer and (screenshot)
@@ -77,7 +82,7 @@ pub trait ImageExt: Sized {
     /// Export image data to file, returns true on success
     fn export(self, filename: impl Display) -> bool;
     /// Export image to memory buffer
-    fn export_to_memory(self, tpe: ImageType) -> Result<Vec<u8>, String>;
+    fn export_to_memory(self, tpe: ImageType) -> Result<Vec<u8>, RaylibError>;
     /// Export image as code file defining an array of bytes, returns true on success
     fn export_as_code(self, filename: impl Display) -> bool;
 
@@ -137,7 +142,7 @@ pub trait ImageExt: Sized {
     /// Modify image color: replace color
     fn color_replace(&mut self, color: Color, replace: Color) -> &mut Self;
     /// Load colors palette from image as a Color array (RGBA - 32bit)
-    fn load_palette(self, max_size: usize) -> Result<Vec<Color>, String>;
+    fn load_palette(self, max_size: usize) -> Result<Vec<Color>, RaylibError>;
     /// Get image alpha border rectangle
     fn get_alpha_border(self, threshold: f32) -> Rectangle;
     /// Get image pixel color at (x, y) position
@@ -220,7 +225,7 @@ pub trait ImageExt: Sized {
 }
 
 impl ImageExt for Image {
-    fn load(filename: impl Display) -> Result<Self, String> {
+    fn load(filename: impl Display) -> Result<Self, RaylibError> {
         RtexturesImpl::__load_image(filename)
     }
 
@@ -230,15 +235,19 @@ impl ImageExt for Image {
         height: i32,
         format: impl Into<usize>,
         header_size: i32,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, RaylibError> {
         RtexturesImpl::__load_image_raw(filename, width, height, format, header_size)
     }
 
-    fn load_svg(filename_or_string: impl Display, width: i32, height: i32) -> Result<Self, String> {
+    fn load_svg(
+        filename_or_string: impl Display,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, RaylibError> {
         RtexturesImpl::__load_image_svg(filename_or_string, width, height)
     }
 
-    fn load_anim(filename: impl Display) -> Result<(Self, i32), String> {
+    fn load_anim(filename: impl Display) -> Result<(Self, i32), RaylibError> {
         RtexturesImpl::__load_image_anim(filename)
     }
 
@@ -250,7 +259,7 @@ impl ImageExt for Image {
         RtexturesImpl::__load_image_from_texture(texture)
     }
 
-    fn load_from_memory(tpe: ImageType, data: &[u8]) -> Result<Self, String> {
+    fn load_from_memory(tpe: ImageType, data: &[u8]) -> Result<Self, RaylibError> {
         RtexturesImpl::__load_image_from_memory(tpe, data)
     }
 
@@ -331,7 +340,7 @@ impl ImageExt for Image {
         RtexturesImpl::__export_image(self, filename)
     }
 
-    fn export_to_memory(self, tpe: ImageType) -> Result<Vec<u8>, String> {
+    fn export_to_memory(self, tpe: ImageType) -> Result<Vec<u8>, RaylibError> {
         RtexturesImpl::__export_image_to_memory(self, tpe)
     }
 
@@ -464,7 +473,7 @@ impl ImageExt for Image {
         self
     }
 
-    fn load_palette(self, max_size: usize) -> Result<Vec<Color>, String> {
+    fn load_palette(self, max_size: usize) -> Result<Vec<Color>, RaylibError> {
         RtexturesImpl::__load_image_pallete(self, max_size as i32)
     }
 
@@ -1,10 +1,11 @@
+use crate::error::RaylibError;
 use crate::rmodels::RmodelsImpl;
 use raylib_ffi::*;
 use std::fmt::Display;
 
 pub trait MaterialExt: Sized {
     /// Load materials from model file
-    fn load(filename: impl Display) -> Result<Vec<Self>, String>;
+    fn load(filename: impl Display) -> Result<Vec<Self>, RaylibError>;
     /// Load default material (Supports: DIFFUSE, SPECULAR, NORMAL maps)
     fn default() -> Self;
 
@@ -17,7 +18,7 @@ pub trait MaterialExt: Sized {
 }
 
 impl MaterialExt for Material {
-    fn load(filename: impl Display) -> Result<Vec<Self>, String> {
+    fn load(filename: impl Display) -> Result<Vec<Self>, RaylibError> {
         RmodelsImpl::__load_materials(filename)
     }
 
@@ -10,6 +10,7 @@ pub mod mesh;
 pub mod model;
 pub mod model_animation;
 pub mod music;
+pub mod owned;
 pub mod ray;
 pub mod sound;
 pub mod texture;
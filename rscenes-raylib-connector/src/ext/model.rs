@@ -1,10 +1,11 @@
+use crate::error::RaylibError;
 use crate::rmodels::RmodelsImpl;
 use raylib_ffi::*;
 use std::fmt::Display;
 
 pub trait ModelExt: Sized {
     /// Load model from files (meshes and materials)
-    fn load(filename: impl Display) -> Result<Self, String>;
+    fn load(filename: impl Display) -> Result<Self, RaylibError>;
     /// Load model from generated mesh (default material)
     fn load_from_mesh(mesh: Mesh) -> Self;
 
@@ -23,7 +24,7 @@ pub trait ModelExt: Sized {
 }
 
 impl ModelExt for Model {
-    fn load(filename: impl Display) -> Result<Self, String> {
+    fn load(filename: impl Display) -> Result<Self, RaylibError> {
         RmodelsImpl::__load_model(filename)
     }
 
@@ -1,10 +1,11 @@
+use crate::error::RaylibError;
 use crate::rmodels::RmodelsImpl;
 use raylib_ffi::*;
 use std::fmt::Display;
 
 pub trait ModelAnimationExt: Sized {
     /// Load model animations from file
-    fn load(filename: impl Display) -> Result<Vec<Self>, String>;
+    fn load(filename: impl Display) -> Result<Vec<Self>, RaylibError>;
     /// Unload animation data
     fn unload(self);
     /// Unload animation array data
@@ -12,7 +13,7 @@ pub trait ModelAnimationExt: Sized {
 }
 
 impl ModelAnimationExt for ModelAnimation {
-    fn load(filename: impl Display) -> Result<Vec<Self>, String> {
+    fn load(filename: impl Display) -> Result<Vec<Self>, RaylibError> {
         RmodelsImpl::__load_model_animations(filename)
     }
 
@@ -1,12 +1,13 @@
+use crate::error::RaylibError;
 use crate::raudio::RaudioImpl;
 use raylib_ffi::*;
 use std::fmt::Display;
 
 pub trait MusicExt: Sized {
     /// Load music stream from file
-    fn load(filename: impl Display) -> Result<Self, String>;
+    fn load(filename: impl Display) -> Result<Self, RaylibError>;
     /// Load music stream from data
-    fn load_from_memory(tpe: impl Display, data: &[u8]) -> Result<Self, String>;
+    fn load_from_memory(tpe: impl Display, data: &[u8]) -> Result<Self, RaylibError>;
 
     /// Check whether a music stream is ready
     fn is_ready(self) -> bool;
@@ -39,11 +40,11 @@ pub trait MusicExt: Sized {
 }
 
 impl MusicExt for Music {
-    fn load(filename: impl Display) -> Result<Self, String> {
+    fn load(filename: impl Display) -> Result<Self, RaylibError> {
         RaudioImpl::__load_music_stream(filename)
     }
 
-    fn load_from_memory(tpe: impl Display, data: &[u8]) -> Result<Self, String> {
+    fn load_from_memory(tpe: impl Display, data: &[u8]) -> Result<Self, RaylibError> {
         RaudioImpl::__load_music_stream_from_memory(tpe, data)
     }
 
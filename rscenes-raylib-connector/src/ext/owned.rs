@@ -0,0 +1,286 @@
+use crate::error::RaylibError;
+use crate::ext::{
+    font::FontExt,
+    image::{ImageExt, ImageType},
+    model::ModelExt,
+    music::MusicExt,
+    sound::SoundExt,
+    texture::{RenderTextureExt, TextureExt},
+    wave::{WaveExt, WaveType},
+};
+use crate::rcore::RcoreImpl;
+use raylib_ffi::*;
+use std::{fmt::Display, mem};
+
+/// Raylib structs holding memory that must be released by hand
+pub trait Resource: Copy {
+    /// Release the resource through the matching `*Ext::unload()`
+    fn release(self);
+}
+
+/// Owned raylib resource, unloaded on drop
+///
+/// Borrow the raw struct through `as_ref()` for drawing calls and the `*Ext` methods, and
+/// change it in place through `with_mut()`. Raw copies are only valid as long as the owner
+/// lives, and must never be unloaded by hand.
+#[derive(Debug)]
+pub struct Owned<T: Resource>(T);
+
+pub type OwnedFont = Owned<Font>;
+pub type OwnedImage = Owned<Image>;
+pub type OwnedModel = Owned<Model>;
+pub type OwnedMusic = Owned<Music>;
+pub type OwnedRenderTexture = Owned<RenderTexture2D>;
+pub type OwnedShader = Owned<Shader>;
+pub type OwnedSound = Owned<Sound>;
+pub type OwnedTexture = Owned<Texture2D>;
+pub type OwnedWave = Owned<Wave>;
+
+impl<T: Resource> Owned<T> {
+    /// Take ownership of a loaded resource
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Give up ownership, the caller becomes responsible for unloading
+    pub fn into_raw(self) -> T {
+        let value = self.0;
+        mem::forget(self);
+        value
+    }
+
+    /// Unload now, same as dropping
+    pub fn unload(self) {}
+
+    /// Run `*Ext` methods changing the resource in place, like `ImageExt::crop()`, keeping
+    /// ownership. Don't unload or assign the raw struct, use `replace()` instead.
+    pub fn with_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.0)
+    }
+
+    /// Take ownership of another resource, handing back the previous one to be unloaded when
+    /// dropped
+    pub fn replace(&mut self, value: T) -> Self {
+        Self(mem::replace(&mut self.0, value))
+    }
+}
+
+impl<T: Resource> AsRef<T> for Owned<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Resource> Drop for Owned<T> {
+    fn drop(&mut self) {
+        self.0.release();
+    }
+}
+
+impl<T: Resource> From<T> for Owned<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl Resource for Font {
+    fn release(self) {
+        FontExt::unload(self)
+    }
+}
+
+impl Resource for Image {
+    fn release(self) {
+        ImageExt::unload(self)
+    }
+}
+
+impl Resource for Model {
+    fn release(self) {
+        ModelExt::unload(self)
+    }
+}
+
+impl Resource for Music {
+    fn release(self) {
+        MusicExt::unload(self)
+    }
+}
+
+impl Resource for RenderTexture2D {
+    fn release(self) {
+        RenderTextureExt::unload(self)
+    }
+}
+
+impl Resource for Shader {
+    fn release(self) {
+        RcoreImpl::__unload_shader(self)
+    }
+}
+
+impl Resource for Sound {
+    fn release(self) {
+        SoundExt::unload(self)
+    }
+}
+
+impl Resource for Texture2D {
+    fn release(self) {
+        TextureExt::unload(self)
+    }
+}
+
+impl Resource for Wave {
+    fn release(self) {
+        WaveExt::unload(self)
+    }
+}
+
+impl OwnedFont {
+    /// Load font from file into GPU memory (VRAM)
+    pub fn load(filename: impl Display) -> Result<Self, RaylibError> {
+        Ok(Self(FontExt::load(filename)?))
+    }
+
+    /// Load font from memory buffer, fileType refers to extension: i.e. '.ttf'
+    pub fn load_from_memory(
+        tpe: impl Display,
+        data: &[u8],
+        font_size: i32,
+    ) -> Result<Self, RaylibError> {
+        Ok(Self(Font::load_from_memory(tpe, data, font_size)?))
+    }
+
+    /// Load font from Image (XNA style)
+    pub fn load_from_image(
+        image: &Image,
+        key: Color,
+        first_char: i32,
+    ) -> Result<Self, RaylibError> {
+        Ok(Self(Font::load_from_image(*image, key, first_char)?))
+    }
+}
+
+impl OwnedImage {
+    /// Load image from file into CPU memory (RAM)
+    pub fn load(filename: impl Display) -> Result<Self, RaylibError> {
+        Ok(Self(ImageExt::load(filename)?))
+    }
+
+    /// Load image from memory buffer
+    pub fn load_from_memory(tpe: ImageType, data: &[u8]) -> Result<Self, RaylibError> {
+        Ok(Self(Image::load_from_memory(tpe, data)?))
+    }
+
+    /// Load image from GPU texture data
+    pub fn load_from_texture(texture: &Texture2D) -> Self {
+        Self(Image::load_from_texture(*texture))
+    }
+
+    /// Load image from screen buffer (screenshot)
+    pub fn load_from_screen() -> Self {
+        Self(Image::load_from_screen())
+    }
+
+    /// Create an owned copy of the image
+    pub fn copy(&self) -> Self {
+        Self(self.0.copy())
+    }
+}
+
+impl OwnedModel {
+    /// Load model from files (meshes and materials)
+    pub fn load(filename: impl Display) -> Result<Self, RaylibError> {
+        Ok(Self(ModelExt::load(filename)?))
+    }
+}
+
+impl OwnedMusic {
+    /// Load music stream from file
+    pub fn load(filename: impl Display) -> Result<Self, RaylibError> {
+        Ok(Self(MusicExt::load(filename)?))
+    }
+
+    /// Load music stream from data, which must outlive the stream
+    pub fn load_from_memory(tpe: impl Display, data: &'static [u8]) -> Result<Self, RaylibError> {
+        Ok(Self(Music::load_from_memory(tpe, data)?))
+    }
+}
+
+impl OwnedRenderTexture {
+    /// Load texture for rendering (framebuffer)
+    pub fn load(width: i32, height: i32) -> Self {
+        Self(RenderTextureExt::load(width, height))
+    }
+}
+
+impl OwnedShader {
+    /// Load shader from files and bind default locations
+    pub fn load(vs_filename: impl Display, fs_filename: impl Display) -> Result<Self, RaylibError> {
+        Ok(Self(RcoreImpl::__load_shader(vs_filename, fs_filename)?))
+    }
+
+    /// Load shader from code strings and bind default locations
+    pub fn load_from_memory(
+        vs_code: impl Display,
+        fs_code: impl Display,
+    ) -> Result<Self, RaylibError> {
+        Ok(Self(RcoreImpl::__load_shader_from_memory(
+            vs_code, fs_code,
+        )?))
+    }
+}
+
+impl OwnedSound {
+    /// Load sound from file
+    pub fn load(filename: impl Display) -> Result<Self, RaylibError> {
+        Ok(Self(SoundExt::load(filename)?))
+    }
+
+    /// Load sound from wave data
+    pub fn load_from_wave(wave: &Wave) -> Self {
+        Self(Sound::load_from_wave(*wave))
+    }
+
+    /// Load sound from memory buffer, going through a temporary wave
+    pub fn load_from_memory(tpe: WaveType, data: &[u8]) -> Result<Self, RaylibError> {
+        let wave = OwnedWave::load_from_memory(tpe, data)?;
+        Ok(Self::load_from_wave(wave.as_ref()))
+    }
+}
+
+impl OwnedTexture {
+    /// Load texture from file into GPU memory (VRAM)
+    pub fn load(filename: impl Display) -> Result<Self, RaylibError> {
+        Ok(Self(TextureExt::load(filename)?))
+    }
+
+    /// Load texture from image data
+    pub fn load_from_image(image: &Image) -> Result<Self, RaylibError> {
+        Ok(Self(Texture2D::load_from_image(*image)?))
+    }
+
+    /// Load texture from memory buffer, going through a temporary image
+    pub fn load_from_memory(tpe: ImageType, data: &[u8]) -> Result<Self, RaylibError> {
+        let image = OwnedImage::load_from_memory(tpe, data)?;
+        Self::load_from_image(image.as_ref())
+    }
+}
+
+impl OwnedWave {
+    /// Load wave data from file
+    pub fn load(filename: impl Display) -> Result<Self, RaylibError> {
+        Ok(Self(WaveExt::load(filename)?))
+    }
+
+    /// Load wave from memory buffer, fileType refers to extension: i.e. '.wav'
+    pub fn load_from_memory(tpe: WaveType, data: &[u8]) -> Result<Self, RaylibError> {
+        Ok(Self(Wave::load_from_memory(tpe, data)?))
+    }
+
+    /// Create an owned copy of the wave
+    pub fn copy(&self) -> Self {
+        Self(self.0.copy())
+    }
+}
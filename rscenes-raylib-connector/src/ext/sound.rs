@@ -1,10 +1,11 @@
+use crate::error::RaylibError;
 use crate::raudio::RaudioImpl;
 use raylib_ffi::*;
 use std::fmt::Display;
 
 pub trait SoundExt: Sized {
     /// Load sound from file
-    fn load(filename: impl Display) -> Result<Self, String>;
+    fn load(filename: impl Display) -> Result<Self, RaylibError>;
     /// Load sound from wave data
     fn load_from_wave(wave: Wave) -> Self;
 
@@ -35,7 +36,7 @@ pub trait SoundExt: Sized {
 }
 
 impl SoundExt for Sound {
-    fn load(filename: impl Display) -> Result<Self, String> {
+    fn load(filename: impl Display) -> Result<Self, RaylibError> {
         RaudioImpl::__load_sound(filename)
     }
 
@@ -1,12 +1,13 @@
+use crate::error::RaylibError;
 use crate::rtextures::RtexturesImpl;
 use raylib_ffi::{enums::*, *};
 use std::fmt::Display;
 
 pub trait TextureExt: Sized {
     /// Load texture from file into GPU memory (VRAM)
-    fn load(filename: impl Display) -> Result<Self, String>;
+    fn load(filename: impl Display) -> Result<Self, RaylibError>;
     /// Load texture from image data
-    fn load_from_image(image: Image) -> Result<Self, String>;
+    fn load_from_image(image: Image) -> Result<Self, RaylibError>;
 
     /// Check whether a texture is ready
     fn is_ready(self) -> bool;
@@ -25,11 +26,11 @@ pub trait TextureExt: Sized {
 }
 
 impl TextureExt for Texture2D {
-    fn load(filename: impl Display) -> Result<Self, String> {
+    fn load(filename: impl Display) -> Result<Self, RaylibError> {
         RtexturesImpl::__load_texture(filename)
     }
 
-    fn load_from_image(image: Image) -> Result<Self, String> {
+    fn load_from_image(image: Image) -> Result<Self, RaylibError> {
         RtexturesImpl::__load_texture_from_image(image)
     }
 
@@ -68,11 +69,11 @@ impl TextureExt for Texture2D {
 }
 
 pub trait TextureCubemapExt: Sized {
-    fn load(image: Image, layout: CubemapLayout) -> Result<Self, String>;
+    fn load(image: Image, layout: CubemapLayout) -> Result<Self, RaylibError>;
 }
 
 impl TextureCubemapExt for TextureCubemap {
-    fn load(image: Image, layout: CubemapLayout) -> Result<Self, String> {
+    fn load(image: Image, layout: CubemapLayout) -> Result<Self, RaylibError> {
         RtexturesImpl::__load_texture_cubemap(image, layout as usize)
     }
 }
@@ -1,10 +1,24 @@
 use crate::rcore::RcoreImpl;
-use raylib_ffi::enums::TraceLogLevel;
-use std::fmt::Display;
+use log::{Level, LevelFilter};
+use raylib_ffi::{enums::TraceLogLevel, va_list};
+use std::{
+    ffi::{c_char, c_int, CStr},
+    fmt::Display,
+    process,
+};
+
+/// Longest message forwarded, raylib's own limit is way shorter
+const MAX_MESSAGE_LENGTH: usize = 1024;
+
+extern "C" {
+    fn vsnprintf(buffer: *mut c_char, size: usize, format: *const c_char, args: va_list) -> c_int;
+}
 
 pub trait TraceLogLevelExt: Sized {
     fn log(self, text: impl Display);
     fn set_default(self);
+    /// Matching `log` crate filter
+    fn to_level_filter(self) -> LevelFilter;
 }
 
 impl TraceLogLevelExt for TraceLogLevel {
@@ -15,4 +29,60 @@ impl TraceLogLevelExt for TraceLogLevel {
     fn set_default(self) {
         RcoreImpl::__set_trace_log_level(self as usize)
     }
+
+    fn to_level_filter(self) -> LevelFilter {
+        match self {
+            Self::All | Self::Trace => LevelFilter::Trace,
+            Self::Debug => LevelFilter::Debug,
+            Self::Info => LevelFilter::Info,
+            Self::Warning => LevelFilter::Warn,
+            Self::Error | Self::Fatal => LevelFilter::Error,
+            Self::None => LevelFilter::Off,
+        }
+    }
+}
+
+/// Trace log callback handing raylib messages to the `log` crate
+pub(crate) unsafe extern "C" fn forward_trace_log(
+    level: c_int,
+    text: *const c_char,
+    args: va_list,
+) {
+    let mut buffer = [0 as c_char; MAX_MESSAGE_LENGTH];
+    if vsnprintf(buffer.as_mut_ptr(), buffer.len(), text, args) < 0 {
+        return;
+    }
+    let message = CStr::from_ptr(buffer.as_ptr()).to_string_lossy();
+    let (target, message) = split_module(&message);
+
+    let fatal = level == TraceLogLevel::Fatal as c_int;
+    let level = match level {
+        level if level <= TraceLogLevel::Trace as c_int => Level::Trace,
+        level if level == TraceLogLevel::Debug as c_int => Level::Debug,
+        level if level == TraceLogLevel::Info as c_int => Level::Info,
+        level if level == TraceLogLevel::Warning as c_int => Level::Warn,
+        _ => Level::Error,
+    };
+    log::log!(target: &target, level, "{}", message);
+
+    // raylib leaves exiting on fatal errors to the callback
+    if fatal {
+        log::logger().flush();
+        process::exit(1);
+    }
+}
+
+/// Messages are prefixed with the raylib module issuing them, like `TEXTURE: `
+fn split_module(message: &str) -> (String, &str) {
+    match message.split_once(": ") {
+        Some((module, rest))
+            if !module.is_empty()
+                && module
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) =>
+        {
+            (format!("raylib::{}", module.to_ascii_lowercase()), rest)
+        }
+        _ => ("raylib".to_owned(), message),
+    }
 }
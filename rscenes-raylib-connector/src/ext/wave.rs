@@ -1,13 +1,14 @@
 /// Set texture wrapping mode
+use crate::error::RaylibError;
 use crate::raudio::RaudioImpl;
 use raylib_ffi::*;
 use std::fmt::Display;
 
 pub trait WaveExt: Sized {
     /// Load wave data from file
-    fn load(filename: impl Display) -> Result<Self, String>;
+    fn load(filename: impl Display) -> Result<Self, RaylibError>;
     /// Load wave from memory buffer, fileType refers to extension: i.e. '.wav'
-    fn load_from_memory(tpe: WaveType, data: &[u8]) -> Result<Self, String>;
+    fn load_from_memory(tpe: WaveType, data: &[u8]) -> Result<Self, RaylibError>;
 
     /// Check whether wave data is ready
     fn is_ready(self) -> bool;
@@ -24,15 +25,15 @@ pub trait WaveExt: Sized {
     /// Convert wave data to desired format
     fn format(&mut self, sample_rate: i32, sample_size: i32, channels: i32);
     /// Load samples data from wave as a 32bit float data array
-    fn load_samples(self) -> Result<Vec<f32>, String>;
+    fn load_samples(self) -> Result<Vec<f32>, RaylibError>;
 }
 
 impl WaveExt for Wave {
-    fn load(filename: impl Display) -> Result<Self, String> {
+    fn load(filename: impl Display) -> Result<Self, RaylibError> {
         RaudioImpl::__load_wave(filename)
     }
 
-    fn load_from_memory(tpe: WaveType, data: &[u8]) -> Result<Self, String> {
+    fn load_from_memory(tpe: WaveType, data: &[u8]) -> Result<Self, RaylibError> {
         RaudioImpl::__load_wave_from_memory(tpe, data)
     }
 
@@ -64,7 +65,7 @@ impl WaveExt for Wave {
         RaudioImpl::__wave_format(self, sample_rate, sample_size, channels)
     }
 
-    fn load_samples(self) -> Result<Vec<f32>, String> {
+    fn load_samples(self) -> Result<Vec<f32>, RaylibError> {
         RaudioImpl::__load_wave_samples(self)
     }
 }
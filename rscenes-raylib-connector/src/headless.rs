@@ -0,0 +1,35 @@
+use std::cell::Cell;
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Fake the resources on the current thread, for headless backends: loading returns zeroed
+/// structs, sized when the size is known, and unloading does nothing, so neither a window nor
+/// an audio device is needed
+pub fn set_enabled(enabled: bool) {
+    ENABLED.with(|cell| cell.set(enabled));
+}
+
+/// Whether the resources are faked on the current thread
+pub fn is_enabled() -> bool {
+    ENABLED.with(Cell::get)
+}
+
+/// Zeroed raylib struct standing for a resource while headless
+pub(crate) fn fake<T: Copy>() -> T {
+    // SAFETY: only used for the plain C resource structs, where zero is a valid "not loaded"
+    unsafe { std::mem::zeroed() }
+}
+
+/// Return early while headless, with the given fake resource if any
+macro_rules! fake_while_headless {
+    () => {
+        fake_while_headless!(())
+    };
+    ($value:expr) => {
+        if $crate::headless::is_enabled() {
+            return $value;
+        }
+    };
+}
@@ -1,6 +1,9 @@
 #![allow(clippy::too_many_arguments)] // Raylib functions has too may arguments ¯\_(ツ)_/¯
 #![allow(clippy::wrong_self_convention)] // Raylib is_* methods receive owned objects
 
+#[macro_use]
+pub mod headless;
+mod error;
 mod ext;
 mod raudio;
 mod rcamera;
@@ -14,6 +17,8 @@ mod rtext;
 mod rtextures;
 mod utils;
 
+pub use error::RaylibError;
+
 pub mod assets {
     pub use crate::ext::audio_stream::AudioStreamExt;
     pub use crate::ext::camera::{Camera2DExt, Camera3DExt};
@@ -27,6 +32,10 @@ pub mod assets {
     pub use crate::ext::model::ModelExt;
     pub use crate::ext::model_animation::ModelAnimationExt;
     pub use crate::ext::music::MusicExt;
+    pub use crate::ext::owned::{
+        Owned, OwnedFont, OwnedImage, OwnedModel, OwnedMusic, OwnedRenderTexture, OwnedShader,
+        OwnedSound, OwnedTexture, OwnedWave, Resource,
+    };
     pub use crate::ext::ray::RayExt;
     pub use crate::ext::sound::SoundExt;
     pub use crate::ext::texture::{RenderTextureExt, TextureCubemapExt, TextureExt};
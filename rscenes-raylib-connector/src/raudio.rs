@@ -1,3 +1,4 @@
+use crate::error::RaylibError;
 use crate::{ext::wave::WaveType, utils::array_from_c};
 use raylib_ffi::*;
 use std::{
@@ -35,20 +36,18 @@ impl RaudioImpl {
 
     // Wave/Sound loading/unloading methods
 
-    pub fn __load_wave(filename: impl Display) -> Result<Wave, String> {
+    pub fn __load_wave(filename: impl Display) -> Result<Wave, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let wave = LoadWave(rl_str!(filename));
             if wave.data.is_null() {
                 if Path::new(&filename.to_string()).exists() {
-                    Err(format!(
+                    Err(RaylibError::Load(format!(
                         "error loading wave from {}, have you initialised the audio device?",
                         filename
-                    ))
+                    )))
                 } else {
-                    Err(format!(
-                        "error loading wave from {}, file not found",
-                        filename
-                    ))
+                    Err(RaylibError::FileNotFound(filename.to_string()))
                 }
             } else {
                 Ok(wave)
@@ -56,15 +55,16 @@ impl RaudioImpl {
         }
     }
 
-    pub fn __load_wave_from_memory(tpe: impl Display, data: &[u8]) -> Result<Wave, String> {
+    pub fn __load_wave_from_memory(tpe: impl Display, data: &[u8]) -> Result<Wave, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let size = data.len() as i32;
             let wave = LoadWaveFromMemory(rl_str!(tpe), data.as_ptr(), size);
             if wave.data.is_null() {
-                Err(
+                Err(RaylibError::Load(
                     "error loading wave from memory, have you initialised the audio device?"
                         .to_owned(),
-                )
+                ))
             } else {
                 Ok(wave)
             }
@@ -75,20 +75,18 @@ impl RaudioImpl {
         unsafe { IsWaveReady(wave) }
     }
 
-    pub fn __load_sound(filename: impl Display) -> Result<Sound, String> {
+    pub fn __load_sound(filename: impl Display) -> Result<Sound, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let sound = LoadSound(rl_str!(filename));
             if sound.stream.channels == 0 {
                 if Path::new(&filename.to_string()).exists() {
-                    Err(format!(
+                    Err(RaylibError::Load(format!(
                         "error loading sound from {}, have you initialised the audio device?",
                         filename
-                    ))
+                    )))
                 } else {
-                    Err(format!(
-                        "error loading sound from {}, file not found",
-                        filename
-                    ))
+                    Err(RaylibError::FileNotFound(filename.to_string()))
                 }
             } else {
                 Ok(sound)
@@ -97,10 +95,12 @@ impl RaudioImpl {
     }
 
     pub fn __load_sound_from_wave(wave: Wave) -> Sound {
+        fake_while_headless!(crate::headless::fake());
         unsafe { LoadSoundFromWave(wave) }
     }
 
     pub fn __load_sound_alias(source: Sound) -> Sound {
+        fake_while_headless!(source);
         unsafe { LoadSoundAlias(source) }
     }
 
@@ -109,14 +109,17 @@ impl RaudioImpl {
     }
 
     pub fn __unload_wave(wave: Wave) {
+        fake_while_headless!();
         unsafe { UnloadWave(wave) }
     }
 
     pub fn __unload_sound(sound: Sound) {
+        fake_while_headless!();
         unsafe { UnloadSound(sound) }
     }
 
     pub fn __unload_sound_alias(sound: Sound) {
+        fake_while_headless!();
         unsafe { UnloadSoundAlias(sound) }
     }
 
@@ -163,6 +166,7 @@ impl RaudioImpl {
     }
 
     pub fn __wave_copy(wave: Wave) -> Wave {
+        fake_while_headless!(wave);
         unsafe { WaveCopy(wave) }
     }
 
@@ -174,13 +178,13 @@ impl RaudioImpl {
         unsafe { WaveFormat(wave, sample_rate, sample_size, channels) }
     }
 
-    pub fn __load_wave_samples(wave: Wave) -> Result<Vec<f32>, String> {
+    pub fn __load_wave_samples(wave: Wave) -> Result<Vec<f32>, RaylibError> {
         unsafe {
             let raw = LoadWaveSamples(wave);
             // TODO: review this calculation
             let count = wave.frameCount * wave.sampleSize / 32;
             let res = array_from_c(raw, count as usize, || {
-                "couldn't load samples from wave".to_owned()
+                RaylibError::Load("couldn't load samples from wave".to_owned())
             })?.to_vec();
             UnloadWaveSamples(raw);
             Ok(res)
@@ -189,11 +193,15 @@ impl RaudioImpl {
 
     // Music management methods
 
-    pub fn __load_music_stream(filename: impl Display) -> Result<Music, String> {
+    pub fn __load_music_stream(filename: impl Display) -> Result<Music, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let music = LoadMusicStream(rl_str!(filename));
             if music.stream.buffer.is_null() {
-                Err(format!("failed to load music stream from {}", filename))
+                Err(RaylibError::Load(format!(
+                    "failed to load music stream from {}",
+                    filename
+                )))
             } else {
                 Ok(music)
             }
@@ -203,13 +211,16 @@ impl RaudioImpl {
     pub fn __load_music_stream_from_memory(
         tpe: impl Display,
         data: &[u8],
-    ) -> Result<Music, String> {
+    ) -> Result<Music, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let size = data.len() as i32;
             let data = data.as_ptr();
             let music = LoadMusicStreamFromMemory(rl_str!(tpe), data, size);
             if music.stream.buffer.is_null() {
-                Err("failed to load music stream from memory".to_owned())
+                Err(RaylibError::Load(
+                    "failed to load music stream from memory".to_owned(),
+                ))
             } else {
                 Ok(music)
             }
@@ -221,6 +232,7 @@ impl RaudioImpl {
     }
 
     pub fn __unload_music_stream(music: Music) {
+        fake_while_headless!();
         unsafe { UnloadMusicStream(music) }
     }
 
@@ -278,7 +290,7 @@ impl RaudioImpl {
         sample_rate: u32,
         sample_size: u32,
         channels: u32,
-    ) -> Result<AudioStream, String> {
+    ) -> Result<AudioStream, RaylibError> {
         unsafe {
             // TODO: test whether audio stream was properly loaded
             Ok(LoadAudioStream(sample_rate, sample_size, channels))
@@ -380,12 +392,12 @@ pub trait Raudio: Debug {
     // Wave/Sound loading/unloading methods
 
     /// Load wave data from file
-    fn load_wave(&self, filename: impl Display) -> Result<Wave, String> {
+    fn load_wave(&self, filename: impl Display) -> Result<Wave, RaylibError> {
         RaudioImpl::__load_wave(filename)
     }
 
     /// Load wave from memory buffer, fileType refers to extension: i.e. '.wav'
-    fn load_wave_from_memory(&self, tpe: WaveType, data: &[u8]) -> Result<Wave, String> {
+    fn load_wave_from_memory(&self, tpe: WaveType, data: &[u8]) -> Result<Wave, RaylibError> {
         RaudioImpl::__load_wave_from_memory(tpe, data)
     }
 
@@ -395,7 +407,7 @@ pub trait Raudio: Debug {
     }
 
     /// Load sound from file
-    fn load_sound(&self, filename: impl Display) -> Result<Sound, String> {
+    fn load_sound(&self, filename: impl Display) -> Result<Sound, RaylibError> {
         RaudioImpl::__load_sound(filename)
     }
 
@@ -497,14 +509,14 @@ pub trait Raudio: Debug {
     }
 
     /// Load samples data from wave as a 32bit float data array
-    fn load_wave_samples(&self, wave: Wave) -> Result<Vec<f32>, String> {
+    fn load_wave_samples(&self, wave: Wave) -> Result<Vec<f32>, RaylibError> {
         RaudioImpl::__load_wave_samples(wave)
     }
 
     // Music management methods
 
     /// Load music stream from file
-    fn load_music_stream(&self, filename: impl Display) -> Result<Music, String> {
+    fn load_music_stream(&self, filename: impl Display) -> Result<Music, RaylibError> {
         RaudioImpl::__load_music_stream(filename)
     }
 
@@ -513,7 +525,7 @@ pub trait Raudio: Debug {
         &self,
         tpe: impl Display,
         data: &[u8],
-    ) -> Result<Music, String> {
+    ) -> Result<Music, RaylibError> {
         RaudioImpl::__load_music_stream_from_memory(tpe, data)
     }
 
@@ -595,7 +607,7 @@ pub trait Raudio: Debug {
         sample_rate: u32,
         sample_size: u32,
         channels: u32,
-    ) -> Result<AudioStream, String> {
+    ) -> Result<AudioStream, RaylibError> {
         RaudioImpl::__load_audio_stream(sample_rate, sample_size, channels)
     }
 
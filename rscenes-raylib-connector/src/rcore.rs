@@ -1,9 +1,12 @@
-use crate::ext::window_handle::WindowHandle;
+use crate::error::RaylibError;
+use crate::ext::{trace_log_level::forward_trace_log, window_handle::WindowHandle};
 use crate::utils::{array_from_c, string_from_c};
 use raylib_ffi::{enums::*, *};
 use std::char;
 use std::ffi::{c_char, c_uchar, c_void};
 use std::fmt::{Debug, Display};
+use std::path::Path;
+use std::ptr;
 
 #[derive(Clone, Copy, Debug, Default)]
 pub(crate) struct RcoreImpl;
@@ -129,11 +132,13 @@ impl RcoreImpl {
         unsafe { SetWindowFocused() }
     }
 
-    pub fn __get_window_handle<'a>() -> Result<WindowHandle<'a>, String> {
+    pub fn __get_window_handle<'a>() -> Result<WindowHandle<'a>, RaylibError> {
         unsafe {
             let raw = GetWindowHandle();
             if raw.is_null() {
-                Err("couldn't get window handle".to_owned())
+                Err(RaylibError::Invalid(
+                    "couldn't get window handle".to_owned(),
+                ))
             } else {
                 Ok(raw.into())
             }
@@ -196,7 +201,7 @@ impl RcoreImpl {
         unsafe { GetWindowScaleDPI() }
     }
 
-    pub fn __get_monitor_name(monitor: i32) -> Result<String, String> {
+    pub fn __get_monitor_name(monitor: i32) -> Result<String, RaylibError> {
         unsafe { string_from_c(GetMonitorName(monitor) as *mut c_char) }
     }
 
@@ -204,7 +209,7 @@ impl RcoreImpl {
         unsafe { SetClipboardText(rl_str!(text)) }
     }
 
-    pub fn __get_clipboard_text() -> Result<String, String> {
+    pub fn __get_clipboard_text() -> Result<String, RaylibError> {
         unsafe { string_from_c(GetClipboardText() as *mut c_char) }
     }
 
@@ -331,14 +336,15 @@ impl RcoreImpl {
     pub fn __load_shader(
         vs_filename: impl Display,
         fs_filename: impl Display,
-    ) -> Result<Shader, String> {
+    ) -> Result<Shader, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let shader = LoadShader(rl_str!(vs_filename), rl_str!(fs_filename));
             if shader.locs.is_null() {
-                Err(format!(
+                Err(RaylibError::Load(format!(
                     "couldn't load shader from [vs]{} [fs]{}",
                     vs_filename, fs_filename
-                ))
+                )))
             } else {
                 Ok(shader)
             }
@@ -348,11 +354,14 @@ impl RcoreImpl {
     pub fn __load_shader_from_memory(
         vs_code: impl Display,
         fs_code: impl Display,
-    ) -> Result<Shader, String> {
+    ) -> Result<Shader, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let shader = LoadShaderFromMemory(rl_str!(vs_code), rl_str!(fs_code));
             if shader.locs.is_null() {
-                Err("failed to load shader from memory".to_owned())
+                Err(RaylibError::Load(
+                    "failed to load shader from memory".to_owned(),
+                ))
             } else {
                 Ok(shader)
             }
@@ -370,7 +379,7 @@ impl RcoreImpl {
     pub fn __get_shader_location_attrib(
         shader: Shader,
         name: impl Display,
-    ) -> Result<ShaderLocationIndex, String> {
+    ) -> Result<ShaderLocationIndex, RaylibError> {
         unsafe {
             match GetShaderLocationAttrib(shader, rl_str!(name)) {
                 0 => Ok(enums::ShaderLocationIndex::VertexPosition),
@@ -399,7 +408,10 @@ impl RcoreImpl {
                 23 => Ok(enums::ShaderLocationIndex::MapIrradiance),
                 24 => Ok(enums::ShaderLocationIndex::MapPrefilter),
                 25 => Ok(enums::ShaderLocationIndex::MapBrdf),
-                num => Err(format!("could not translate location {}", num)),
+                num => Err(RaylibError::Invalid(format!(
+                    "could not translate location {}",
+                    num
+                ))),
             }
         }
     }
@@ -440,6 +452,7 @@ impl RcoreImpl {
     }
 
     pub fn __unload_shader(shader: Shader) {
+        fake_while_headless!();
         unsafe { UnloadShader(shader) }
     }
 
@@ -520,11 +533,15 @@ impl RcoreImpl {
         unsafe { GetRandomValue(min, max) }
     }
 
-    pub fn __load_random_sequence(count: usize, min: i32, max: i32) -> Result<Vec<i32>, String> {
+    pub fn __load_random_sequence(
+        count: usize,
+        min: i32,
+        max: i32,
+    ) -> Result<Vec<i32>, RaylibError> {
         unsafe {
             let raw = LoadRandomSequence(count as u32, min, max);
             let res = array_from_c(raw, count, || {
-                "could not generate random sequence".to_owned()
+                RaylibError::Invalid("could not generate random sequence".to_owned())
             })?
             .to_vec();
             UnloadRandomSequence(raw);
@@ -554,6 +571,10 @@ impl RcoreImpl {
         unsafe { SetTraceLogLevel(level.into() as i32) }
     }
 
+    pub fn __set_trace_log_callback(callback: TraceLogCallback) {
+        unsafe { SetTraceLogCallback(callback) }
+    }
+
     // pub fn __mem_alloc(size: usize) -> *mut c_void {
     //     unsafe { MemAlloc(size as u32) }
     // }
@@ -568,12 +589,12 @@ impl RcoreImpl {
 
     // Files management methods
 
-    pub fn __load_file_data(filename: impl Display) -> Result<Vec<u8>, String> {
+    pub fn __load_file_data(filename: impl Display) -> Result<Vec<u8>, RaylibError> {
         unsafe {
             let mut size = 0;
             let raw = LoadFileData(rl_str!(filename), &mut size);
             let res = array_from_c(raw, size as usize, || {
-                format!("couldn't load file data from {}", filename)
+                RaylibError::Load(format!("couldn't load file data from {}", filename))
             })?
             .to_vec();
             UnloadFileData(raw);
@@ -596,7 +617,7 @@ impl RcoreImpl {
         }
     }
 
-    pub fn __load_file_text(filename: impl Display) -> Result<String, String> {
+    pub fn __load_file_text(filename: impl Display) -> Result<String, RaylibError> {
         unsafe {
             let raw = LoadFileText(rl_str!(filename)) as *mut c_char;
             let res = string_from_c(raw);
@@ -627,31 +648,31 @@ impl RcoreImpl {
     //     unsafe { GetFileLength(rl_str!(filename)) }
     // }
     //
-    // pub fn __get_file_extenstion(filename: impl Display) -> Result<String, String> {
+    // pub fn __get_file_extenstion(filename: impl Display) -> Result<String, RaylibError> {
     //     unsafe { string_from_c(GetFileExtension(rl_str!(filename)) as *mut c_char) }
     // }
     //
-    // pub fn __get_file_name(path: impl Display) -> Result<String, String> {
+    // pub fn __get_file_name(path: impl Display) -> Result<String, RaylibError> {
     //     unsafe { string_from_c(GetFileName(rl_str!(path)) as *mut c_char) }
     // }
     //
-    // pub fn __get_file_name_without_ext(path: impl Display) -> Result<String, String> {
+    // pub fn __get_file_name_without_ext(path: impl Display) -> Result<String, RaylibError> {
     //     unsafe { string_from_c(GetFileNameWithoutExt(rl_str!(path)) as *mut c_char) }
     // }
     //
-    // pub fn __get_directory_path(path: impl Display) -> Result<String, String> {
+    // pub fn __get_directory_path(path: impl Display) -> Result<String, RaylibError> {
     //     unsafe { string_from_c(GetDirectoryPath(rl_str!(path)) as *mut c_char) }
     // }
     //
-    // pub fn __get_prev_directory_path(path: impl Display) -> Result<String, String> {
+    // pub fn __get_prev_directory_path(path: impl Display) -> Result<String, RaylibError> {
     //     unsafe { string_from_c(GetPrevDirectoryPath(rl_str!(path)) as *mut c_char) }
     // }
     //
-    // pub fn __get_working_directory() -> Result<String, String> {
+    // pub fn __get_working_directory() -> Result<String, RaylibError> {
     //     unsafe { string_from_c(GetWorkingDirectory() as *mut c_char) }
     // }
 
-    pub fn __get_application_directory() -> Result<String, String> {
+    pub fn __get_application_directory() -> Result<String, RaylibError> {
         unsafe { string_from_c(GetApplicationDirectory() as *mut c_char) }
     }
 
@@ -690,14 +711,14 @@ impl RcoreImpl {
     // pub fn __unload_dropped_files(files: FilePathList) {
     //     unsafe { UnloadDroppedFiles(files) }
     // }
-    //
-    // pub fn __get_file_mod_time(filename: impl Display) -> i64 {
-    //     unsafe { GetFileModTime(rl_str!(filename)) }
-    // }
+
+    pub fn __get_file_mod_time(filename: impl Display) -> i64 {
+        unsafe { GetFileModTime(rl_str!(filename)) as i64 }
+    }
 
     // Compression/Encoding functionality
 
-    // pub fn __compress_data(data: &mut Vec<u8>) -> Result<Vec<u8>, String> {
+    // pub fn __compress_data(data: &mut Vec<u8>) -> Result<Vec<u8>, RaylibError> {
     //     unsafe {
     //         let size = data.len() as i32;
     //         let data = data.as_mut_ptr() as *mut c_uchar;
@@ -709,7 +730,7 @@ impl RcoreImpl {
     //     }
     // }
     //
-    // pub fn __decompress_data(data: &mut Vec<u8>) -> Result<Vec<u8>, String> {
+    // pub fn __decompress_data(data: &mut Vec<u8>) -> Result<Vec<u8>, RaylibError> {
     //     unsafe {
     //         let size = data.len() as i32;
     //         let data = data.as_mut_ptr() as *mut c_uchar;
@@ -721,7 +742,7 @@ impl RcoreImpl {
     //     }
     // }
     //
-    // pub fn __encode_data_base64(data: &mut Vec<u8>) -> Result<String, String> {
+    // pub fn __encode_data_base64(data: &mut Vec<u8>) -> Result<String, RaylibError> {
     //     unsafe {
     //         let size = data.len() as i32;
     //         let data = data.as_mut_ptr() as *mut c_uchar;
@@ -730,7 +751,7 @@ impl RcoreImpl {
     //     }
     // }
     //
-    // pub fn __decode_data_base64(data: &str) -> Result<Vec<u8>, String> {
+    // pub fn __decode_data_base64(data: &str) -> Result<Vec<u8>, RaylibError> {
     //     unsafe {
     //         let mut size: i32 = 0;
     //         let raw = DecodeDataBase64(rl_str!(data) as *const c_uchar, &mut size);
@@ -742,19 +763,43 @@ impl RcoreImpl {
 
     // Automation events functionality
 
+    pub fn __new_automation_event_list() -> AutomationEventList {
+        unsafe { LoadAutomationEventList(ptr::null()) }
+    }
+
     pub fn __load_automation_event_list(
         filename: impl Display,
-    ) -> Result<AutomationEventList, String> {
+    ) -> Result<AutomationEventList, RaylibError> {
+        // raylib hands an empty list back instead of failing on missing files
+        if !Path::new(&filename.to_string()).is_file() {
+            return Err(RaylibError::FileNotFound(filename.to_string()));
+        }
         unsafe {
             let list = LoadAutomationEventList(rl_str!(filename));
             if list.events.is_null() {
-                Err(format!("couldn't load automation events from {}", filename))
+                Err(RaylibError::Load(format!(
+                    "couldn't load automation events from {}",
+                    filename
+                )))
             } else {
                 Ok(list)
             }
         }
     }
 
+    pub fn __load_automation_events(
+        filename: impl Display,
+    ) -> Result<Vec<AutomationEvent>, RaylibError> {
+        let list = Self::__load_automation_event_list(&filename)?;
+        let events = unsafe {
+            array_from_c(list.events, list.count as usize, || {
+                RaylibError::Load(format!("couldn't load automation events from {}", filename))
+            })
+        };
+        Self::__unload_automation_event_list(list);
+        events
+    }
+
     pub fn __unload_automation_event_list(mut list: AutomationEventList) {
         unsafe { UnloadAutomationEventList(&mut list) }
     }
@@ -766,8 +811,8 @@ impl RcoreImpl {
         unsafe { ExportAutomationEventList(list, rl_str!(filename)) }
     }
 
-    pub fn __set_automation_event_list(mut list: AutomationEventList) {
-        unsafe { SetAutomationEventList(&mut list) }
+    pub fn __set_automation_event_list(list: &mut AutomationEventList) {
+        unsafe { SetAutomationEventList(list) }
     }
 
     pub fn __set_automation_event_base_frame(frame: i32) {
@@ -942,7 +987,7 @@ impl RcoreImpl {
         unsafe { IsGamepadAvailable(gamepad) }
     }
 
-    pub fn __get_gamepad_name(gamepad: i32) -> Result<String, String> {
+    pub fn __get_gamepad_name(gamepad: i32) -> Result<String, RaylibError> {
         unsafe { string_from_c(GetGamepadName(gamepad) as *mut c_char) }
     }
 
@@ -1225,7 +1270,7 @@ pub trait Rcore: Debug {
     }
 
     /// Get native window handle
-    fn get_window_handle(&self) -> Result<WindowHandle<'_>, String> {
+    fn get_window_handle(&self) -> Result<WindowHandle<'_>, RaylibError> {
         RcoreImpl::__get_window_handle()
     }
 
@@ -1332,7 +1377,7 @@ pub trait Rcore: Debug {
     }
 
     /// Get the human-readable, UTF-8 encoded name of the specified monitor
-    fn get_monitor_name(&self, monitor: i32) -> Result<String, String> {
+    fn get_monitor_name(&self, monitor: i32) -> Result<String, RaylibError> {
         RcoreImpl::__get_monitor_name(monitor)
     }
 
@@ -1342,7 +1387,7 @@ pub trait Rcore: Debug {
     }
 
     /// Get clipboard text content
-    fn get_clipboard_text(&self) -> Result<String, String> {
+    fn get_clipboard_text(&self) -> Result<String, RaylibError> {
         RcoreImpl::__get_clipboard_text()
     }
 
@@ -1558,7 +1603,7 @@ pub trait Rcore: Debug {
         &self,
         vs_filename: impl Display,
         fs_filename: impl Display,
-    ) -> Result<Shader, String> {
+    ) -> Result<Shader, RaylibError> {
         RcoreImpl::__load_shader(vs_filename, fs_filename)
     }
 
@@ -1567,7 +1612,7 @@ pub trait Rcore: Debug {
         &self,
         vs_code: impl Display,
         fs_code: impl Display,
-    ) -> Result<Shader, String> {
+    ) -> Result<Shader, RaylibError> {
         RcoreImpl::__load_shader_from_memory(vs_code, fs_code)
     }
 
@@ -1586,7 +1631,7 @@ pub trait Rcore: Debug {
         &self,
         shader: Shader,
         name: impl Display,
-    ) -> Result<enums::ShaderLocationIndex, String> {
+    ) -> Result<enums::ShaderLocationIndex, RaylibError> {
         RcoreImpl::__get_shader_location_attrib(shader, name)
     }
 
@@ -1765,10 +1810,16 @@ pub trait Rcore: Debug {
         RcoreImpl::__set_trace_log_level(level as usize)
     }
 
+    /// Forward trace log messages to the `log` crate, targeted after the raylib module issuing
+    /// them (`raylib::texture`, `raylib::audio`...)
+    fn forward_trace_log(&self) {
+        RcoreImpl::__set_trace_log_callback(Some(forward_trace_log))
+    }
+
     // Files management methods
 
     /// Load file data as byte array (read)
-    fn load_file_data(&self, filename: impl Display) -> Result<Vec<u8>, String> {
+    fn load_file_data(&self, filename: impl Display) -> Result<Vec<u8>, RaylibError> {
         RcoreImpl::__load_file_data(filename)
     }
 
@@ -1783,7 +1834,7 @@ pub trait Rcore: Debug {
     }
 
     /// Load text data from file (read)
-    fn load_file_text(&self, filename: impl Display) -> Result<String, String> {
+    fn load_file_text(&self, filename: impl Display) -> Result<String, RaylibError> {
         RcoreImpl::__load_file_text(filename)
     }
 
@@ -1795,20 +1846,38 @@ pub trait Rcore: Debug {
     // File system methods
 
     /// Get the directory of the running application
-    fn get_application_directory(&self) -> Result<String, String> {
+    fn get_application_directory(&self) -> Result<String, RaylibError> {
         RcoreImpl::__get_application_directory()
     }
 
+    /// Get file modification time (last write time), zero when it can't be read
+    fn get_file_mod_time(&self, filename: impl Display) -> i64 {
+        RcoreImpl::__get_file_mod_time(filename)
+    }
+
     // Automation events functionality
 
-    /// Load automation events list from file, NULL for empty list, capacity = MAX_AUTOMATION_EVENTS
+    /// Create an empty automation events list, capacity = MAX_AUTOMATION_EVENTS
+    fn new_automation_event_list(&self) -> AutomationEventList {
+        RcoreImpl::__new_automation_event_list()
+    }
+
+    /// Load automation events list from file, capacity = MAX_AUTOMATION_EVENTS
     fn load_automation_event_list(
         &self,
         filename: impl Display,
-    ) -> Result<AutomationEventList, String> {
+    ) -> Result<AutomationEventList, RaylibError> {
         RcoreImpl::__load_automation_event_list(filename)
     }
 
+    /// Load automation events from file, releasing the list right away
+    fn load_automation_events(
+        &self,
+        filename: impl Display,
+    ) -> Result<Vec<AutomationEvent>, RaylibError> {
+        RcoreImpl::__load_automation_events(filename)
+    }
+
     /// Unload automation events list from file
     fn unload_automation_event_list(&self, list: AutomationEventList) {
         RcoreImpl::__unload_automation_event_list(list)
@@ -1823,8 +1892,8 @@ pub trait Rcore: Debug {
         RcoreImpl::__export_automation_event_list(list, filename)
     }
 
-    /// Set automation event list to record to
-    fn set_automation_event_list(&self, list: AutomationEventList) {
+    /// Set automation event list to record to, it must stay in place while recording
+    fn set_automation_event_list(&self, list: &mut AutomationEventList) {
         RcoreImpl::__set_automation_event_list(list)
     }
 
@@ -1898,7 +1967,7 @@ pub trait Rcore: Debug {
     }
 
     /// Get gamepad internal name id
-    fn get_gamepad_name(&self, gamepad: i32) -> Result<String, String> {
+    fn get_gamepad_name(&self, gamepad: i32) -> Result<String, RaylibError> {
         RcoreImpl::__get_gamepad_name(gamepad)
     }
 
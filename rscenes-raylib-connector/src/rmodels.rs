@@ -1,3 +1,4 @@
+use crate::error::RaylibError;
 use crate::utils::array_from_c;
 use raylib_ffi::*;
 use std::{
@@ -162,18 +163,23 @@ impl RmodelsImpl {
 
     // Model management methods
 
-    pub fn __load_model(filename: impl Display) -> Result<Model, String> {
+    pub fn __load_model(filename: impl Display) -> Result<Model, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let model = LoadModel(rl_str!(filename));
             if model.meshCount > 0 {
                 Ok(model)
             } else {
-                Err(format!("couldn't load model from {}", filename))
+                Err(RaylibError::Load(format!(
+                    "couldn't load model from {}",
+                    filename
+                )))
             }
         }
     }
 
     pub fn __load_model_from_mesh(mesh: Mesh) -> Model {
+        fake_while_headless!(crate::headless::fake());
         unsafe { LoadModelFromMesh(mesh) }
     }
 
@@ -182,6 +188,7 @@ impl RmodelsImpl {
     }
 
     pub fn __unload_model(model: Model) {
+        fake_while_headless!();
         unsafe { UnloadModel(model) }
     }
 
@@ -355,12 +362,12 @@ impl RmodelsImpl {
 
     // Material loading/unloading methods
 
-    pub fn __load_materials(filename: impl Display) -> Result<Vec<Material>, String> {
+    pub fn __load_materials(filename: impl Display) -> Result<Vec<Material>, RaylibError> {
         unsafe {
             let mut count: i32 = 0;
             let raw = LoadMaterials(rl_str!(filename), &mut count);
             array_from_c(raw, count as usize, || {
-                format!("couldn't load material from {}", filename)
+                RaylibError::Load(format!("couldn't load material from {}", filename))
             })
         }
     }
@@ -387,12 +394,14 @@ impl RmodelsImpl {
 
     // Model animations loading/unloading methods
 
-    pub fn __load_model_animations(filename: impl Display) -> Result<Vec<ModelAnimation>, String> {
+    pub fn __load_model_animations(
+        filename: impl Display,
+    ) -> Result<Vec<ModelAnimation>, RaylibError> {
         unsafe {
             let mut count: i32 = 0;
             let raw = LoadModelAnimations(rl_str!(filename), &mut count);
             array_from_c(raw, count as usize, || {
-                format!("couldn't load model animations from {}", filename)
+                RaylibError::Load(format!("couldn't load model animations from {}", filename))
             })
         }
     }
@@ -612,7 +621,7 @@ pub trait Rmodels: Debug {
     // Model management methods
 
     /// Load model from files (meshes and materials)
-    fn load_model(&self, filename: impl Display) -> Result<Model, String> {
+    fn load_model(&self, filename: impl Display) -> Result<Model, RaylibError> {
         RmodelsImpl::__load_model(filename)
     }
 
@@ -831,7 +840,7 @@ pub trait Rmodels: Debug {
     // Material loading/unloading methods
 
     /// Load materials from model file
-    fn load_materials(&self, filename: impl Display) -> Result<Vec<Material>, String> {
+    fn load_materials(&self, filename: impl Display) -> Result<Vec<Material>, RaylibError> {
         RmodelsImpl::__load_materials(filename)
     }
 
@@ -863,7 +872,10 @@ pub trait Rmodels: Debug {
     // Model animations loading/unloading methods
 
     /// Load model animations from file
-    fn load_model_animations(&self, filename: impl Display) -> Result<Vec<ModelAnimation>, String> {
+    fn load_model_animations(
+        &self,
+        filename: impl Display,
+    ) -> Result<Vec<ModelAnimation>, RaylibError> {
         RmodelsImpl::__load_model_animations(filename)
     }
 
@@ -1,3 +1,4 @@
+use crate::error::RaylibError;
 use crate::{
     ext::codepoints::Codepoints,
     utils::{string_from_c, utf8_from_c},
@@ -20,18 +21,19 @@ impl RtextImpl {
         unsafe { GetFontDefault() }
     }
 
-    pub fn __load_font(filename: impl Display) -> Result<Font, String> {
+    pub fn __load_font(filename: impl Display) -> Result<Font, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let font = LoadFont(rl_str!(filename));
             if font.baseSize > 0 {
                 Ok(font)
             } else if Path::new(&format!("{}", filename)).exists() {
-                Err(format!("couldn't load font from {}", filename))
-            } else {
-                Err(format!(
-                    "couldn't load font from {}, file not found",
+                Err(RaylibError::Load(format!(
+                    "couldn't load font from {}",
                     filename
-                ))
+                )))
+            } else {
+                Err(RaylibError::FileNotFound(filename.to_string()))
             }
         }
     }
@@ -40,19 +42,20 @@ impl RtextImpl {
         filename: impl Display,
         font_size: i32,
         codepoints: Codepoints,
-    ) -> Result<Font, String> {
+    ) -> Result<Font, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let count = codepoints.count as i32;
             let font = LoadFontEx(rl_str!(filename), font_size, codepoints.into(), count);
             if font.baseSize > 0 {
                 Ok(font)
             } else if Path::new(&format!("{}", filename)).exists() {
-                Err(format!("couldn't load font from {}", filename))
-            } else {
-                Err(format!(
-                    "couldn't load font from {}, file not found",
+                Err(RaylibError::Load(format!(
+                    "couldn't load font from {}",
                     filename
-                ))
+                )))
+            } else {
+                Err(RaylibError::FileNotFound(filename.to_string()))
             }
         }
     }
@@ -61,13 +64,16 @@ impl RtextImpl {
         image: Image,
         key: Color,
         first_char: i32,
-    ) -> Result<Font, String> {
+    ) -> Result<Font, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let font = LoadFontFromImage(image, key, first_char);
             if font.baseSize > 0 {
                 Ok(font)
             } else {
-                Err("couldn't load font from image".to_owned())
+                Err(RaylibError::Load(
+                    "couldn't load font from image".to_owned(),
+                ))
             }
         }
     }
@@ -77,7 +83,8 @@ impl RtextImpl {
         data: &[u8],
         font_size: i32,
         codepoints: Codepoints,
-    ) -> Result<Font, String> {
+    ) -> Result<Font, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let data_size = data.len() as i32;
             let mut data = data.to_vec();
@@ -93,7 +100,9 @@ impl RtextImpl {
             if font.baseSize > 0 {
                 Ok(font)
             } else {
-                Err("couldn't load font from memory".to_owned())
+                Err(RaylibError::Load(
+                    "couldn't load font from memory".to_owned(),
+                ))
             }
         }
     }
@@ -107,6 +116,7 @@ impl RtextImpl {
     // TODO: UnloadFontData
 
     pub fn __unload_font(font: Font) {
+        fake_while_headless!();
         unsafe { UnloadFont(font) }
     }
 
@@ -224,7 +234,7 @@ impl RtextImpl {
 
     // Text codepoints management methods (unicode characters)
 
-    pub fn __load_utf8(codepoints: Codepoints) -> Result<String, String> {
+    pub fn __load_utf8(codepoints: Codepoints) -> Result<String, RaylibError> {
         unsafe {
             let length = codepoints.len() as i32;
             let raw = LoadUTF8(codepoints.into(), length);
@@ -236,14 +246,17 @@ impl RtextImpl {
 
     // TOD: UnloadUTF8
 
-    pub fn __load_codepoints(text: impl Display) -> Result<Codepoints, String> {
+    pub fn __load_codepoints(text: impl Display) -> Result<Codepoints, RaylibError> {
         unsafe {
             let c_text = rl_str!(text);
             let mut count: i32 = 0;
             let ptr = LoadCodepoints(c_text, &mut count);
             let raw = Codepoints::new(ptr, count);
             if raw.inner.is_null() {
-                Err(format!("couldn't load codepoints from: {}", text))
+                Err(RaylibError::Load(format!(
+                    "couldn't load codepoints from: {}",
+                    text
+                )))
             } else {
                 Ok(raw)
             }
@@ -258,49 +271,55 @@ impl RtextImpl {
         unsafe { GetCodepointCount(rl_str!(text)) }
     }
 
-    pub fn __get_codepoint(text: impl Display) -> Result<(i32, usize), String> {
+    pub fn __get_codepoint(text: impl Display) -> Result<(i32, usize), RaylibError> {
         unsafe {
             let mut size: i32 = 0;
             let cp = GetCodepoint(rl_str!(text), &mut size);
             if cp == 0x3f {
-                Err("error trying to get codepoint".to_owned())
+                Err(RaylibError::Invalid(
+                    "error trying to get codepoint".to_owned(),
+                ))
             } else if size == 0 {
-                Err("no codepoint found".to_owned())
+                Err(RaylibError::Invalid("no codepoint found".to_owned()))
             } else {
                 Ok((cp, size as usize))
             }
         }
     }
 
-    pub fn __get_codepoint_next(text: impl Display) -> Result<(i32, usize), String> {
+    pub fn __get_codepoint_next(text: impl Display) -> Result<(i32, usize), RaylibError> {
         unsafe {
             let mut size: i32 = 0;
             let cp = GetCodepointNext(rl_str!(text), &mut size);
             if cp == 0x3f {
-                Err("error trying to get codepoint".to_owned())
+                Err(RaylibError::Invalid(
+                    "error trying to get codepoint".to_owned(),
+                ))
             } else if size == 0 {
-                Err("no codepoint found".to_owned())
+                Err(RaylibError::Invalid("no codepoint found".to_owned()))
             } else {
                 Ok((cp, size as usize))
             }
         }
     }
 
-    pub fn __get_codepoint_previous(text: impl Display) -> Result<(i32, usize), String> {
+    pub fn __get_codepoint_previous(text: impl Display) -> Result<(i32, usize), RaylibError> {
         unsafe {
             let mut size: i32 = 0;
             let cp = GetCodepointPrevious(rl_str!(text), &mut size);
             if cp == 0x3f {
-                Err("error trying to get codepoint".to_owned())
+                Err(RaylibError::Invalid(
+                    "error trying to get codepoint".to_owned(),
+                ))
             } else if size == 0 {
-                Err("no codepoint found".to_owned())
+                Err(RaylibError::Invalid("no codepoint found".to_owned()))
             } else {
                 Ok((cp, size as usize))
             }
         }
     }
 
-    pub fn __codepoint_to_utf8(codepoint: i32) -> Result<String, String> {
+    pub fn __codepoint_to_utf8(codepoint: i32) -> Result<String, RaylibError> {
         unsafe {
             let mut size: i32 = 0;
             let raw = CodepointToUTF8(codepoint, &mut size) as *const u8;
@@ -339,7 +358,7 @@ pub trait Rtext: Debug {
     }
 
     /// Load font from file into GPU memory (VRAM)
-    fn load_font(&self, filename: impl Display) -> Result<Font, String> {
+    fn load_font(&self, filename: impl Display) -> Result<Font, RaylibError> {
         RtextImpl::__load_font(filename)
     }
 
@@ -349,7 +368,7 @@ pub trait Rtext: Debug {
         filename: impl Display,
         font_size: i32,
         codepoints: Codepoints,
-    ) -> Result<Font, String> {
+    ) -> Result<Font, RaylibError> {
         RtextImpl::__load_font_ex(filename, font_size, codepoints)
     }
 
@@ -359,7 +378,7 @@ pub trait Rtext: Debug {
         image: Image,
         key: Color,
         first_char: i32,
-    ) -> Result<Font, String> {
+    ) -> Result<Font, RaylibError> {
         RtextImpl::__load_font_from_image(image, key, first_char)
     }
 
@@ -370,7 +389,7 @@ pub trait Rtext: Debug {
         data: &[u8],
         font_size: i32,
         codepoints: Codepoints,
-    ) -> Result<Font, String> {
+    ) -> Result<Font, RaylibError> {
         RtextImpl::__load_font_from_memory(tpe, data, font_size, codepoints)
     }
 
@@ -497,12 +516,12 @@ pub trait Rtext: Debug {
     // Text codepoints management methods (unicode characters)
 
     /// Load UTF-8 text encoded from codepoints array
-    fn load_utf8(&self, codepoints: Codepoints) -> Result<String, String> {
+    fn load_utf8(&self, codepoints: Codepoints) -> Result<String, RaylibError> {
         RtextImpl::__load_utf8(codepoints)
     }
 
     /// Load all codepoints from a UTF-8 text string, codepoints count returned by parameter
-    fn load_codepoints(&self, text: impl Display) -> Result<Codepoints, String> {
+    fn load_codepoints(&self, text: impl Display) -> Result<Codepoints, RaylibError> {
         RtextImpl::__load_codepoints(text)
     }
 
@@ -517,22 +536,22 @@ pub trait Rtext: Debug {
     }
 
     /// Get next codepoint in a UTF-8 encoded string, 0x3f('?') is returned on failure
-    fn get_codepoint(&self, text: impl Display) -> Result<(i32, usize), String> {
+    fn get_codepoint(&self, text: impl Display) -> Result<(i32, usize), RaylibError> {
         RtextImpl::__get_codepoint(text)
     }
 
     /// Get next codepoint in a UTF-8 encoded string, 0x3f('?') is returned on failure
-    fn get_codepoint_next(&self, text: impl Display) -> Result<(i32, usize), String> {
+    fn get_codepoint_next(&self, text: impl Display) -> Result<(i32, usize), RaylibError> {
         RtextImpl::__get_codepoint_next(text)
     }
 
     /// Get previous codepoint in a UTF-8 encoded string, 0x3f('?') is returned on failure
-    fn get_codepoint_previous(&self, text: impl Display) -> Result<(i32, usize), String> {
+    fn get_codepoint_previous(&self, text: impl Display) -> Result<(i32, usize), RaylibError> {
         RtextImpl::__get_codepoint_previous(text)
     }
 
     /// Encode one codepoint into UTF-8 byte array (array length returned as parameter)
-    fn codepoint_to_utf8(&self, codepoint: i32) -> Result<String, String> {
+    fn codepoint_to_utf8(&self, codepoint: i32) -> Result<String, RaylibError> {
         RtextImpl::__codepoint_to_utf8(codepoint)
     }
 }
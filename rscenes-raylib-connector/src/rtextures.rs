@@ -1,3 +1,4 @@
+use crate::error::RaylibError;
 use crate::{ext::image::ImageType, utils::array_from_c};
 use raylib_ffi::{enums::*, *};
 use std::{
@@ -14,17 +15,18 @@ pub(crate) struct RtexturesImpl;
 impl RtexturesImpl {
     // Image loading
 
-    pub fn __load_image(filename: impl Display) -> Result<Image, String> {
+    pub fn __load_image(filename: impl Display) -> Result<Image, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let image = LoadImage(rl_str!(filename));
             if image.data.is_null() {
                 if Path::new(&format!("{}", filename)).exists() {
-                    Err(format!("couldn't load image from {}", filename))
-                } else {
-                    Err(format!(
-                        "couldn't load image from {}, file not found",
+                    Err(RaylibError::Load(format!(
+                        "couldn't load image from {}",
                         filename
-                    ))
+                    )))
+                } else {
+                    Err(RaylibError::FileNotFound(filename.to_string()))
                 }
             } else {
                 Ok(image)
@@ -38,7 +40,8 @@ impl RtexturesImpl {
         height: i32,
         format: impl Into<usize>,
         header_size: i32,
-    ) -> Result<Image, String> {
+    ) -> Result<Image, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let image = LoadImageRaw(
                 rl_str!(filename),
@@ -49,12 +52,12 @@ impl RtexturesImpl {
             );
             if image.data.is_null() {
                 if Path::new(&format!("{}", filename)).exists() {
-                    Err(format!("couldn't load image from {}", filename))
-                } else {
-                    Err(format!(
-                        "couldn't load image from {}, file not found",
+                    Err(RaylibError::Load(format!(
+                        "couldn't load image from {}",
                         filename
-                    ))
+                    )))
+                } else {
+                    Err(RaylibError::FileNotFound(filename.to_string()))
                 }
             } else {
                 Ok(image)
@@ -66,29 +69,34 @@ impl RtexturesImpl {
         filename_or_string: impl Display,
         width: i32,
         height: i32,
-    ) -> Result<Image, String> {
+    ) -> Result<Image, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let image = LoadImageSvg(rl_str!(filename_or_string), width, height);
             if image.data.is_null() {
-                Err(format!("couldn't load image from {}", filename_or_string))
+                Err(RaylibError::Load(format!(
+                    "couldn't load image from {}",
+                    filename_or_string
+                )))
             } else {
                 Ok(image)
             }
         }
     }
 
-    pub fn __load_image_anim(filename: impl Display) -> Result<(Image, i32), String> {
+    pub fn __load_image_anim(filename: impl Display) -> Result<(Image, i32), RaylibError> {
+        fake_while_headless!(Ok((crate::headless::fake(), 1)));
         unsafe {
             let mut frames: i32 = 0;
             let image = LoadImageAnim(rl_str!(filename), &mut frames);
             if image.data.is_null() {
                 if Path::new(&format!("{}", filename)).exists() {
-                    Err(format!("couldn't load image from {}", filename))
-                } else {
-                    Err(format!(
-                        "couldn't load image from {}, file not found",
+                    Err(RaylibError::Load(format!(
+                        "couldn't load image from {}",
                         filename
-                    ))
+                    )))
+                } else {
+                    Err(RaylibError::FileNotFound(filename.to_string()))
                 }
             } else {
                 Ok((image, frames))
@@ -97,14 +105,17 @@ impl RtexturesImpl {
     }
 
     // FIXME: failing to load PNG
-    pub fn __load_image_from_memory(tpe: impl Display, data: &[u8]) -> Result<Image, String> {
+    pub fn __load_image_from_memory(tpe: impl Display, data: &[u8]) -> Result<Image, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let size = data.len() as i32;
             let mut data = data.to_vec();
             let data = data.as_mut_ptr();
             let image = LoadImageFromMemory(rl_str!(tpe), data, size);
             if image.data.is_null() {
-                Err("failed to load image from memory".to_owned())
+                Err(RaylibError::Load(
+                    "failed to load image from memory".to_owned(),
+                ))
             } else {
                 Ok(image)
             }
@@ -112,10 +123,16 @@ impl RtexturesImpl {
     }
 
     pub fn __load_image_from_texture(texture: Texture2D) -> Image {
+        fake_while_headless!(Image {
+            width: texture.width,
+            height: texture.height,
+            ..crate::headless::fake()
+        });
         unsafe { LoadImageFromTexture(texture) }
     }
 
     pub fn __load_image_from_screen() -> Image {
+        fake_while_headless!(crate::headless::fake());
         unsafe { LoadImageFromScreen() }
     }
 
@@ -124,6 +141,7 @@ impl RtexturesImpl {
     }
 
     pub fn __unload_image(image: Image) {
+        fake_while_headless!();
         unsafe { UnloadImage(image) }
     }
 
@@ -131,11 +149,16 @@ impl RtexturesImpl {
         unsafe { ExportImage(image, rl_str!(filename)) }
     }
 
-    pub fn __export_image_to_memory(image: Image, tpe: impl Display) -> Result<Vec<u8>, String> {
+    pub fn __export_image_to_memory(
+        image: Image,
+        tpe: impl Display,
+    ) -> Result<Vec<u8>, RaylibError> {
         unsafe {
             let mut size: i32 = 0;
             let raw = ExportImageToMemory(image, rl_str!(tpe), &mut size);
-            array_from_c(raw, size as usize, || "failed to export image".to_owned())
+            array_from_c(raw, size as usize, || {
+                RaylibError::Load("failed to export image".to_owned())
+            })
         }
     }
 
@@ -218,6 +241,7 @@ impl RtexturesImpl {
     // Image manipulation methods
 
     pub fn __image_copy(image: Image) -> Image {
+        fake_while_headless!(image);
         unsafe { ImageCopy(image) }
     }
 
@@ -349,12 +373,12 @@ impl RtexturesImpl {
         unsafe { LoadImageColors(image) }
     }
 
-    pub fn __load_image_pallete(image: Image, max_size: i32) -> Result<Vec<Color>, String> {
+    pub fn __load_image_pallete(image: Image, max_size: i32) -> Result<Vec<Color>, RaylibError> {
         unsafe {
             let mut size: i32 = 0;
             let raw = LoadImagePalette(image, max_size, &mut size);
             let res = array_from_c(raw, size as usize, || {
-                "failed to load pallet from image".to_owned()
+                RaylibError::Load("failed to load pallet from image".to_owned())
             })?
             // Copy array elements to the stack
             .iter()
@@ -522,17 +546,18 @@ impl RtexturesImpl {
 
     // Texture loading methods
 
-    pub fn __load_texture(filename: impl Display) -> Result<Texture2D, String> {
+    pub fn __load_texture(filename: impl Display) -> Result<Texture2D, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let texture = LoadTexture(rl_str!(filename));
             if texture.id == 0 {
                 if Path::new(&format!("{}", filename)).exists() {
-                    Err(format!("couldn't load texture from {}", filename))
-                } else {
-                    Err(format!(
-                        "couldn't load texture from {}, file not found",
+                    Err(RaylibError::Load(format!(
+                        "couldn't load texture from {}",
                         filename
-                    ))
+                    )))
+                } else {
+                    Err(RaylibError::FileNotFound(filename.to_string()))
                 }
             } else {
                 Ok(texture)
@@ -540,11 +565,18 @@ impl RtexturesImpl {
         }
     }
 
-    pub fn __load_texture_from_image(image: Image) -> Result<Texture2D, String> {
+    pub fn __load_texture_from_image(image: Image) -> Result<Texture2D, RaylibError> {
+        fake_while_headless!(Ok(Texture2D {
+            width: image.width,
+            height: image.height,
+            ..crate::headless::fake()
+        }));
         unsafe {
             let texture = LoadTextureFromImage(image);
             if texture.id == 0 {
-                Err("failed to load texture from image".to_owned())
+                Err(RaylibError::Load(
+                    "failed to load texture from image".to_owned(),
+                ))
             } else {
                 Ok(texture)
             }
@@ -554,11 +586,14 @@ impl RtexturesImpl {
     pub fn __load_texture_cubemap(
         image: Image,
         layout: impl Into<usize>,
-    ) -> Result<TextureCubemap, String> {
+    ) -> Result<TextureCubemap, RaylibError> {
+        fake_while_headless!(Ok(crate::headless::fake()));
         unsafe {
             let texture = LoadTextureCubemap(image, layout.into() as i32);
             if texture.id == 0 {
-                Err("failed to load cubemap from image".to_owned())
+                Err(RaylibError::Load(
+                    "failed to load cubemap from image".to_owned(),
+                ))
             } else {
                 Ok(texture)
             }
@@ -566,6 +601,18 @@ impl RtexturesImpl {
     }
 
     pub fn __load_render_texture(width: i32, height: i32) -> RenderTexture2D {
+        fake_while_headless!({
+            let texture = Texture2D {
+                width,
+                height,
+                ..crate::headless::fake()
+            };
+            RenderTexture2D {
+                texture,
+                depth: texture,
+                ..crate::headless::fake()
+            }
+        });
         unsafe { LoadRenderTexture(width, height) }
     }
 
@@ -574,6 +621,7 @@ impl RtexturesImpl {
     }
 
     pub fn __unload_texture(texture: Texture2D) {
+        fake_while_headless!();
         unsafe { UnloadTexture(texture) }
     }
 
@@ -582,6 +630,7 @@ impl RtexturesImpl {
     }
 
     pub fn __unload_render_texture(target: RenderTexture2D) {
+        fake_while_headless!();
         unsafe { UnloadRenderTexture(target) }
     }
 
@@ -726,7 +775,7 @@ pub trait Rtextures: Debug {
     // Image loading
 
     /// Load image from file into CPU memory (RAM)
-    fn load_image(&self, filename: impl Display) -> Result<Image, String> {
+    fn load_image(&self, filename: impl Display) -> Result<Image, RaylibError> {
         RtexturesImpl::__load_image(filename)
     }
 
@@ -738,7 +787,7 @@ pub trait Rtextures: Debug {
         height: i32,
         format: PixelFormat,
         header_size: i32,
-    ) -> Result<Image, String> {
+    ) -> Result<Image, RaylibError> {
         RtexturesImpl::__load_image_raw(filename, width, height, format as usize, header_size)
     }
 
@@ -748,17 +797,17 @@ pub trait Rtextures: Debug {
         filename_or_string: impl Display,
         width: i32,
         height: i32,
-    ) -> Result<Image, String> {
+    ) -> Result<Image, RaylibError> {
         RtexturesImpl::__load_image_svg(filename_or_string, width, height)
     }
 
     /// Load image sequence from file (frames appended to image.data)
-    fn load_image_anim(&self, filename: impl Display) -> Result<(Image, i32), String> {
+    fn load_image_anim(&self, filename: impl Display) -> Result<(Image, i32), RaylibError> {
         RtexturesImpl::__load_image_anim(filename)
     }
 
     /// Load image from memory buffer, fileType refers to extension: i.e. '.png'
-    fn load_image_from_memory(&self, tpe: ImageType, data: &[u8]) -> Result<Image, String> {
+    fn load_image_from_memory(&self, tpe: ImageType, data: &[u8]) -> Result<Image, RaylibError> {
         RtexturesImpl::__load_image_from_memory(tpe, data)
     }
 
@@ -788,7 +837,7 @@ pub trait Rtextures: Debug {
     }
 
     /// Export image to memory buffer
-    fn export_image_to_memory(&self, image: Image, tpe: ImageType) -> Result<Vec<u8>, String> {
+    fn export_image_to_memory(&self, image: Image, tpe: ImageType) -> Result<Vec<u8>, RaylibError> {
         RtexturesImpl::__export_image_to_memory(image, tpe)
     }
 
@@ -1043,7 +1092,7 @@ pub trait Rtextures: Debug {
     }
 
     /// Load colors palette from image as a Color array (RGBA - 32bit)
-    fn load_image_pallete(&self, image: Image, max_size: i32) -> Result<Vec<Color>, String> {
+    fn load_image_pallete(&self, image: Image, max_size: i32) -> Result<Vec<Color>, RaylibError> {
         RtexturesImpl::__load_image_pallete(image, max_size)
     }
 
@@ -1219,12 +1268,12 @@ pub trait Rtextures: Debug {
     // Texture loading methods
 
     /// Load texture from file into GPU memory (VRAM)
-    fn load_texture(&self, filename: impl Display) -> Result<Texture2D, String> {
+    fn load_texture(&self, filename: impl Display) -> Result<Texture2D, RaylibError> {
         RtexturesImpl::__load_texture(filename)
     }
 
     /// Load texture from image data
-    fn load_texture_from_image(&self, image: Image) -> Result<Texture2D, String> {
+    fn load_texture_from_image(&self, image: Image) -> Result<Texture2D, RaylibError> {
         RtexturesImpl::__load_texture_from_image(image)
     }
 
@@ -1233,7 +1282,7 @@ pub trait Rtextures: Debug {
         &self,
         image: Image,
         layout: CubemapLayout,
-    ) -> Result<TextureCubemap, String> {
+    ) -> Result<TextureCubemap, RaylibError> {
         RtexturesImpl::__load_texture_cubemap(image, layout as usize)
     }
 
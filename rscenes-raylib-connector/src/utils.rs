@@ -3,22 +3,24 @@ use std::{
     ptr, slice,
 };
 
-pub unsafe fn string_from_c(raw: *mut c_char) -> Result<String, String> {
+use crate::error::RaylibError;
+
+pub unsafe fn string_from_c(raw: *mut c_char) -> Result<String, RaylibError> {
     CString::from_raw(raw)
-        .into_string().map_err(|e| format!("{}", e))
+        .into_string().map_err(RaylibError::from)
 }
 
-pub unsafe fn utf8_from_c(raw: *const u8, size: usize) -> Result<String, String> {
+pub unsafe fn utf8_from_c(raw: *const u8, size: usize) -> Result<String, RaylibError> {
     let bytes = slice::from_raw_parts(raw, size);
     std::str::from_utf8(bytes)
-        .map(|s| s.to_owned()).map_err(|e| format!("{}", e))
+        .map(|s| s.to_owned()).map_err(RaylibError::from)
 }
 
 pub unsafe fn array_from_c<T>(
     raw: *mut T,
     size: usize,
-    error: impl FnOnce() -> String,
-) -> Result<Vec<T>, String>
+    error: impl FnOnce() -> RaylibError,
+) -> Result<Vec<T>, RaylibError>
 where
     T: Clone,
 {
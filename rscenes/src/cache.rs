@@ -0,0 +1,496 @@
+use crate::connectors::PlainConnector;
+use rscenes_raylib_connector::{assets::*, interface::*, RaylibError};
+use std::{cell::Cell, collections::HashMap, fmt, fmt::Display, hash::Hash, rc::Rc};
+
+/// Cheap, reference-counted handle to a loaded resource
+///
+/// The resource is unloaded when the last handle is dropped, including the one kept by the
+/// [`AssetCache`] that loaded it. Use `get()` for the raw raylib struct when drawing: the cache
+/// may swap it for a fresh copy when hot reloading.
+pub struct Asset<T: Resource>(Rc<Slot<T>>);
+
+struct Slot<T: Resource>(Cell<T>);
+
+impl<T: Resource> Drop for Slot<T> {
+    fn drop(&mut self) {
+        self.0.get().release();
+    }
+}
+
+impl<T: Resource> Asset<T> {
+    /// Take ownership of an already loaded resource
+    pub fn new(value: T) -> Self {
+        Self(Rc::new(Slot(Cell::new(value))))
+    }
+
+    /// The current raw resource, don't keep it around across frames
+    pub fn get(&self) -> T {
+        self.0 .0.get()
+    }
+
+    /// How many handles currently share the resource
+    pub fn handles(this: &Self) -> usize {
+        Rc::strong_count(&this.0)
+    }
+
+    /// Swap in a reloaded resource for every handle, unloading the previous one
+    fn replace(&self, value: Owned<T>) {
+        self.0 .0.replace(value.into_raw()).release();
+    }
+}
+
+impl<T: Resource> Clone for Asset<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Resource> From<Owned<T>> for Asset<T> {
+    fn from(value: Owned<T>) -> Self {
+        Self::new(value.into_raw())
+    }
+}
+
+impl<T: Resource + fmt::Debug> fmt::Debug for Asset<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Asset").field(&self.get()).finish()
+    }
+}
+
+/// Where a cached resource came from
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Source {
+    /// Files are watched for changes when hot reloading
+    Files(Vec<String>),
+    /// Embedded bytes are identified by address, so they must be `'static`
+    Memory {
+        address: usize,
+        len: usize,
+        variant: i32,
+    },
+}
+
+impl Source {
+    fn path(path: impl Display) -> Self {
+        Self::Files(vec![path.to_string()])
+    }
+
+    fn memory(data: &'static [u8], variant: i32) -> Self {
+        Self::Memory {
+            address: data.as_ptr() as usize,
+            len: data.len(),
+            variant,
+        }
+    }
+
+    /// Latest modification time among the source files
+    fn modified(&self) -> i64 {
+        match self {
+            Self::Files(files) => files
+                .iter()
+                .map(|file| PlainConnector.get_file_mod_time(file))
+                .max()
+                .unwrap_or_default(),
+            Self::Memory { .. } => 0,
+        }
+    }
+}
+
+type Loader<T> = Box<dyn Fn() -> Result<Owned<T>, RaylibError>>;
+
+struct Entry<T: Resource> {
+    asset: Asset<T>,
+    modified: i64,
+    load: Loader<T>,
+}
+
+struct Store<T: Resource>(HashMap<Source, Entry<T>>);
+
+impl<T: Resource> Store<T> {
+    fn get_or_load(
+        &mut self,
+        source: Source,
+        modified: impl FnOnce(&Source) -> i64,
+        load: impl Fn() -> Result<Owned<T>, RaylibError> + 'static,
+    ) -> Result<Asset<T>, RaylibError> {
+        if let Some(entry) = self.0.get(&source) {
+            return Ok(entry.asset.clone());
+        }
+        let asset = Asset::from(load()?);
+        let entry = Entry {
+            asset: asset.clone(),
+            modified: modified(&source),
+            load: Box::new(load),
+        };
+        self.0.insert(source, entry);
+        Ok(asset)
+    }
+
+    /// Reload file-backed resources which files changed, return whether any was reloaded
+    fn reload_changed(&mut self, modified: impl Fn(&Source) -> i64) -> bool {
+        let mut reloaded = false;
+        for (source, entry) in self.0.iter_mut() {
+            if !matches!(source, Source::Files(_)) {
+                continue;
+            }
+            let current = modified(source);
+            if current == entry.modified {
+                continue;
+            }
+            // Broken files are retried once they change again
+            entry.modified = current;
+            match (entry.load)() {
+                Ok(value) => {
+                    entry.asset.replace(value);
+                    reloaded = true;
+                }
+                Err(err) => log::warn!("reloading {:?}: {}", source, err),
+            }
+        }
+        reloaded
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn purge(&mut self) {
+        self.0.retain(|_, entry| Asset::handles(&entry.asset) > 1);
+    }
+}
+
+impl<T: Resource> Default for Store<T> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<T: Resource> fmt::Debug for Store<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+/// Deduplicating loader for textures, shaders, sounds, fonts, music and models
+///
+/// Keep one in the scene that owns the resources: it is dropped along with the scene on
+/// teardown, unloading everything no other handle still refers to. Return it from
+/// `Scene::assets()` to have the manager hot reload the files it loaded.
+#[derive(Debug, Default)]
+pub struct AssetCache {
+    textures: Store<Texture2D>,
+    shaders: Store<Shader>,
+    sounds: Store<Sound>,
+    fonts: Store<Font>,
+    music: Store<Music>,
+    models: Store<Model>,
+}
+
+impl AssetCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load texture from file into GPU memory (VRAM)
+    pub fn texture(&mut self, path: impl Display) -> Result<Asset<Texture2D>, RaylibError> {
+        let path = path.to_string();
+        self.textures
+            .get_or_load(Source::path(&path), Source::modified, move || {
+                OwnedTexture::load(&path)
+            })
+    }
+
+    /// Load texture from an embedded image
+    pub fn texture_from_memory(
+        &mut self,
+        tpe: ImageType,
+        data: &'static [u8],
+    ) -> Result<Asset<Texture2D>, RaylibError> {
+        self.textures
+            .get_or_load(Source::memory(data, 0), Source::modified, move || {
+                OwnedTexture::load_from_memory(tpe, data)
+            })
+    }
+
+    /// Load shader from vertex and fragment shader files
+    pub fn shader(
+        &mut self,
+        vs_filename: impl Display,
+        fs_filename: impl Display,
+    ) -> Result<Asset<Shader>, RaylibError> {
+        let vs = vs_filename.to_string();
+        let fs = fs_filename.to_string();
+        let source = Source::Files(vec![vs.clone(), fs.clone()]);
+        self.shaders.get_or_load(source, Source::modified, move || {
+            OwnedShader::load(&vs, &fs)
+        })
+    }
+
+    /// Load sound from file
+    pub fn sound(&mut self, path: impl Display) -> Result<Asset<Sound>, RaylibError> {
+        let path = path.to_string();
+        self.sounds
+            .get_or_load(Source::path(&path), Source::modified, move || {
+                OwnedSound::load(&path)
+            })
+    }
+
+    /// Load sound from an embedded wave
+    pub fn sound_from_memory(
+        &mut self,
+        tpe: WaveType,
+        data: &'static [u8],
+    ) -> Result<Asset<Sound>, RaylibError> {
+        self.sounds
+            .get_or_load(Source::memory(data, 0), Source::modified, move || {
+                OwnedSound::load_from_memory(tpe, data)
+            })
+    }
+
+    /// Load font from file into GPU memory (VRAM)
+    pub fn font(&mut self, path: impl Display) -> Result<Asset<Font>, RaylibError> {
+        let path = path.to_string();
+        self.fonts
+            .get_or_load(Source::path(&path), Source::modified, move || {
+                OwnedFont::load(&path)
+            })
+    }
+
+    /// Load font from an embedded buffer, fileType refers to extension: i.e. '.ttf'
+    pub fn font_from_memory(
+        &mut self,
+        tpe: impl Display,
+        data: &'static [u8],
+        font_size: i32,
+    ) -> Result<Asset<Font>, RaylibError> {
+        let tpe = tpe.to_string();
+        self.fonts.get_or_load(
+            Source::memory(data, font_size),
+            Source::modified,
+            move || OwnedFont::load_from_memory(&tpe, data, font_size),
+        )
+    }
+
+    /// Load music stream from file
+    pub fn music(&mut self, path: impl Display) -> Result<Asset<Music>, RaylibError> {
+        let path = path.to_string();
+        self.music
+            .get_or_load(Source::path(&path), Source::modified, move || {
+                OwnedMusic::load(&path)
+            })
+    }
+
+    /// Load music stream from an embedded buffer, fileType refers to extension: i.e. '.ogg'
+    ///
+    /// The stream keeps reading from `data`, hence the `'static` requirement.
+    pub fn music_from_memory(
+        &mut self,
+        tpe: impl Display,
+        data: &'static [u8],
+    ) -> Result<Asset<Music>, RaylibError> {
+        let tpe = tpe.to_string();
+        self.music
+            .get_or_load(Source::memory(data, 0), Source::modified, move || {
+                OwnedMusic::load_from_memory(&tpe, data)
+            })
+    }
+
+    /// Load model from file (meshes and materials)
+    pub fn model(&mut self, path: impl Display) -> Result<Asset<Model>, RaylibError> {
+        let path = path.to_string();
+        self.models
+            .get_or_load(Source::path(&path), Source::modified, move || {
+                OwnedModel::load(&path)
+            })
+    }
+
+    /// Reload resources which files were modified since loaded, return whether any was
+    ///
+    /// Every handle sees the new resource, resources failing to reload are kept as they were.
+    pub fn reload_changed(&mut self) -> bool {
+        // Not short-circuiting, every store gets checked
+        self.textures.reload_changed(Source::modified)
+            | self.shaders.reload_changed(Source::modified)
+            | self.sounds.reload_changed(Source::modified)
+            | self.fonts.reload_changed(Source::modified)
+            | self.music.reload_changed(Source::modified)
+            | self.models.reload_changed(Source::modified)
+    }
+
+    /// Count the resources held
+    pub fn len(&self) -> usize {
+        self.textures.len()
+            + self.shaders.len()
+            + self.sounds.len()
+            + self.fonts.len()
+            + self.music.len()
+            + self.models.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forget resources no handle outside the cache refers to, unloading them
+    pub fn purge(&mut self) {
+        self.textures.purge();
+        self.shaders.purge();
+        self.sounds.purge();
+        self.fonts.purge();
+        self.music.purge();
+        self.models.purge();
+    }
+
+    /// Forget every resource, those still referred to are unloaded along with their last handle
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static RELEASED: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct Fake(u32);
+
+    impl Resource for Fake {
+        fn release(self) {
+            RELEASED.with(|released| released.borrow_mut().push(self.0));
+        }
+    }
+
+    fn released() -> Vec<u32> {
+        RELEASED.with(|released| released.borrow().clone())
+    }
+
+    fn unchanged(_: &Source) -> i64 {
+        0
+    }
+
+    fn load(store: &mut Store<Fake>, path: &str, value: u32) -> Asset<Fake> {
+        store
+            .get_or_load(Source::path(path), unchanged, move || {
+                Ok(Owned::new(Fake(value)))
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_load_each_source_once() {
+        let mut store = Store::<Fake>::default();
+        let first = load(&mut store, "ball.png", 1);
+        let second = load(&mut store, "ball.png", 2);
+        assert_eq!(first.get().0, 1);
+        assert_eq!(second.get().0, 1);
+        assert_eq!(Asset::handles(&first), 3);
+    }
+
+    #[test]
+    fn it_should_tell_embedded_buffers_apart() {
+        static BALL: &[u8] = b"ball";
+        static PLUCK: &[u8] = b"pluck";
+        assert_eq!(Source::memory(BALL, 0), Source::memory(BALL, 0));
+        assert_ne!(Source::memory(BALL, 0), Source::memory(PLUCK, 0));
+        assert_ne!(Source::memory(BALL, 16), Source::memory(BALL, 32));
+    }
+
+    #[test]
+    fn it_should_not_cache_failures() {
+        let mut store = Store::<Fake>::default();
+        let res = store.get_or_load(Source::path("missing.png"), unchanged, || {
+            Err(RaylibError::FileNotFound("missing.png".to_owned()))
+        });
+        assert!(res.is_err());
+        let asset = load(&mut store, "missing.png", 3);
+        assert_eq!(asset.get().0, 3);
+    }
+
+    #[test]
+    fn it_should_unload_with_the_last_handle() {
+        let mut store = Store::<Fake>::default();
+        let asset = load(&mut store, "a", 4);
+        let other = asset.clone();
+        drop(store);
+        drop(asset);
+        assert!(!released().contains(&4));
+        drop(other);
+        assert!(released().contains(&4));
+    }
+
+    #[test]
+    fn it_should_purge_unused_resources() {
+        let mut store = Store::<Fake>::default();
+        let kept = load(&mut store, "kept", 5);
+        load(&mut store, "unused", 6);
+        store.purge();
+        assert!(released().contains(&6));
+        assert!(!released().contains(&5));
+        assert_eq!(Asset::handles(&kept), 2);
+        assert_eq!(store.0.len(), 1);
+    }
+
+    #[test]
+    fn it_should_reload_changed_files_for_every_handle() {
+        let mut store = Store::<Fake>::default();
+        let version = Rc::new(Cell::new(7));
+        let loaded = version.clone();
+        let asset = store
+            .get_or_load(Source::path("hero.png"), unchanged, move || {
+                Ok(Owned::new(Fake(loaded.get())))
+            })
+            .unwrap();
+        let other = asset.clone();
+        load(&mut store, "still.png", 8);
+        assert!(!store.reload_changed(unchanged));
+
+        version.set(9);
+        let touched = |source: &Source| (*source == Source::path("hero.png")) as i64;
+        assert!(store.reload_changed(touched));
+        assert_eq!(other.get().0, 9);
+        assert!(released().contains(&7));
+        assert!(!released().contains(&8));
+        assert!(!store.reload_changed(touched));
+    }
+
+    #[test]
+    fn it_should_keep_resources_failing_to_reload() {
+        let mut store = Store::<Fake>::default();
+        let broken = Rc::new(Cell::new(false));
+        let failing = broken.clone();
+        let asset = store
+            .get_or_load(Source::path("broken.png"), unchanged, move || {
+                if failing.get() {
+                    Err(RaylibError::Load("broken.png".to_owned()))
+                } else {
+                    Ok(Owned::new(Fake(10)))
+                }
+            })
+            .unwrap();
+        broken.set(true);
+        assert!(!store.reload_changed(|_| 1));
+        assert_eq!(asset.get().0, 10);
+        assert!(!released().contains(&10));
+    }
+
+    #[test]
+    fn it_should_not_watch_embedded_buffers() {
+        static DATA: &[u8] = b"data";
+        let mut store = Store::<Fake>::default();
+        store
+            .get_or_load(Source::memory(DATA, 0), unchanged, || {
+                Ok(Owned::new(Fake(11)))
+            })
+            .unwrap();
+        assert!(!store.reload_changed(|_| 1));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    config::{level, parse_size, Config},
+    error::Error,
+    replay::Replay,
+};
+use rscenes_raylib_connector::assets::TraceLogLevel;
+use std::{cell::RefCell, env, str::FromStr};
+
+thread_local! {
+    static OPTIONS: RefCell<Options> = RefCell::new(Options::default());
+}
+
+/// Standard command-line options of the games built on Rscenes
+///
+/// Handed to [`Rscenes::set_options()`](crate::prelude::Rscenes::set_options), they override
+/// the matching config settings. Scenes read them back with `PlainConnector::get_options()`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Options {
+    /// `--fullscreen` or `--windowed`
+    pub fullscreen: Option<bool>,
+    /// `--size WIDTHxHEIGHT`
+    pub size: Option<(i32, i32)>,
+    /// `--monitor N`
+    pub monitor: Option<i32>,
+    /// `--fps N`
+    pub fps: Option<i32>,
+    /// `--log-level LEVEL`
+    pub log_level: Option<TraceLogLevel>,
+    /// `--mute`
+    pub mute: bool,
+    /// `--start-scene NAME`
+    pub start_scene: Option<String>,
+    /// `--record FILE` or `--replay FILE`
+    pub replay: Option<Replay>,
+    /// Arguments left for the game: unknown ones and everything after `--`
+    pub rest: Vec<String>,
+}
+
+impl Options {
+    /// Help text for the standard options, for games to print along with theirs
+    pub const USAGE: &'static str = "  \
+  --windowed            start in a window
+  --fullscreen          start in fullscreen mode
+  --size WIDTHxHEIGHT   window size
+  --monitor N           monitor to start on
+  --fps N               target frames per second, 0 for unlimited
+  --log-level LEVEL     all, trace, debug, info, warning, error, fatal or none
+  --mute                start with the sound off
+  --start-scene NAME    scene to start from
+  --record FILE         record the input session into FILE
+  --replay FILE         play back the input session recorded into FILE
+";
+
+    /// Parse the process arguments
+    pub fn from_args() -> Result<Self, Error> {
+        Self::parse(env::args().skip(1))
+    }
+
+    /// Parse the given arguments, without the program name
+    ///
+    /// Values come either as the next argument or after `=`, like `--size=800x600`.
+    pub fn parse<I>(args: I) -> Result<Self, Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut options = Self::default();
+        let mut args = args.into_iter().map(Into::into);
+
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                options.rest.extend(args);
+                break;
+            }
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => (name, Some(value.to_owned())),
+                _ => (arg.as_str(), None),
+            };
+            let flag = || match inline {
+                Some(_) => Err(Error::Usage(format!("{name} takes no value"))),
+                None => Ok(true),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| Error::Usage(format!("missing value for {name}")))
+            };
+
+            match name {
+                "--windowed" => options.fullscreen = Some(!flag()?),
+                "--fullscreen" => options.fullscreen = Some(flag()?),
+                "--mute" => options.mute = flag()?,
+                "--size" => {
+                    let value = value()?;
+                    options.size = Some(parse_size(&value).ok_or_else(|| invalid(name, &value))?);
+                }
+                "--monitor" => options.monitor = Some(number(name, &value()?)?),
+                "--fps" => options.fps = Some(number(name, &value()?)?),
+                "--log-level" => {
+                    let value = value()?;
+                    options.log_level =
+                        Some(level::parse(&value).ok_or_else(|| invalid(name, &value))?);
+                }
+                "--start-scene" => options.start_scene = Some(value()?),
+                "--record" => options.replay = Some(Replay::record(value()?)),
+                "--replay" => options.replay = Some(Replay::play(value()?)),
+                _ => options.rest.push(arg.clone()),
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Override the settings given in the command line
+    pub(crate) fn apply(&self, config: &mut Config, replay: &mut Option<Replay>) {
+        if let Some(fullscreen) = self.fullscreen {
+            config.fullscreen = fullscreen;
+        }
+        if let Some(size) = self.size {
+            config.size = size;
+        }
+        if self.monitor.is_some() {
+            config.monitor = self.monitor;
+        }
+        if let Some(fps) = self.fps {
+            config.fps = fps;
+        }
+        if let Some(log_level) = self.log_level {
+            config.log_level = log_level;
+        }
+        config.mute |= self.mute;
+        if self.replay.is_some() {
+            replay.clone_from(&self.replay);
+        }
+    }
+}
+
+fn invalid(name: &str, value: &str) -> Error {
+    Error::Usage(format!("invalid {name}: {value}"))
+}
+
+fn number<T: FromStr>(name: &str, value: &str) -> Result<T, Error> {
+    value.parse().map_err(|_| invalid(name, value))
+}
+
+pub(crate) fn set_options(options: Options) {
+    OPTIONS.with(|current| *current.borrow_mut() = options);
+}
+
+pub(crate) fn get_options() -> Options {
+    OPTIONS.with(|current| current.borrow().clone())
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::report;
+
+    #[test]
+    fn it_should_parse_standard_options() {
+        let options = Options::parse([
+            "--fullscreen",
+            "--size",
+            "1024x768",
+            "--monitor=1",
+            "--fps",
+            "144",
+            "--log-level=warning",
+            "--mute",
+            "--start-scene",
+            "gameplay",
+            "--replay",
+            "session.rec",
+        ])
+        .unwrap();
+        assert_eq!(
+            options,
+            Options {
+                fullscreen: Some(true),
+                size: Some((1024, 768)),
+                monitor: Some(1),
+                fps: Some(144),
+                log_level: Some(TraceLogLevel::Warning),
+                mute: true,
+                start_scene: Some("gameplay".to_owned()),
+                replay: Some(Replay::play("session.rec")),
+                rest: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_leave_other_arguments_to_the_game() {
+        let options =
+            Options::parse(["puzzle.txt", "--windowed", "--hard", "--", "--fps", "5"]).unwrap();
+        assert_eq!(options.fullscreen, Some(false));
+        assert_eq!(options.fps, None);
+        assert_eq!(options.rest, vec!["puzzle.txt", "--hard", "--fps", "5"]);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_options() {
+        let err = |args: &[&str]| report(&Options::parse(args.iter().copied()).unwrap_err());
+        assert_eq!(err(&["--fps", "fast"]), "usage error: invalid --fps: fast");
+        assert_eq!(err(&["--size", "800"]), "usage error: invalid --size: 800");
+        assert_eq!(
+            err(&["--log-level"]),
+            "usage error: missing value for --log-level"
+        );
+        assert_eq!(err(&["--mute=no"]), "usage error: --mute takes no value");
+    }
+
+    #[test]
+    fn it_should_override_config() {
+        let options = Options::parse(["--size=640x480", "--record", "out.rec"]).unwrap();
+        let mut config = Config {
+            size: (800, 600),
+            fullscreen: true,
+            fps: 30,
+            ..Default::default()
+        };
+        let mut replay = None;
+        options.apply(&mut config, &mut replay);
+        assert_eq!(config.size, (640, 480));
+        assert!(config.fullscreen);
+        assert_eq!(config.fps, 30);
+        assert!(
+            matches!(replay, Some(Replay::Record { path, .. }) if path.as_os_str() == "out.rec")
+        );
+    }
+}
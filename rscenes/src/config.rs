@@ -0,0 +1,430 @@
+use crate::{error::report, logger::Logger};
+use rscenes_raylib_connector::{assets::*, interface::*};
+use std::path::PathBuf;
+
+#[cfg(feature = "config")]
+use crate::{error::Error, input::code};
+#[cfg(feature = "config")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "config")]
+use std::{env, fs, path::Path};
+
+#[cfg(feature = "config")]
+const ENV_PREFIX: &str = "RSCENES_";
+
+/// Window and runtime settings applied by [`Rscenes`](crate::prelude::Rscenes) on start
+///
+/// With the `config` feature it can be loaded from a TOML or JSON file, missing fields taking
+/// their default values, and then overridden by `RSCENES_*` environment variables.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "config", derive(Serialize, Deserialize), serde(default))]
+pub struct Config {
+    /// Window title
+    pub title: String,
+    /// Window startup geometry, zero takes the screen dimension
+    pub size: (i32, i32),
+    /// Start in fullscreen mode
+    pub fullscreen: bool,
+    /// Monitor to place the window on, the primary one if unset
+    pub monitor: Option<i32>,
+    /// Smallest size the window can be resized to
+    pub min_size: Option<(i32, i32)>,
+    /// Largest size the window can be resized to
+    pub max_size: Option<(i32, i32)>,
+    /// Target frames per second, zero for unlimited
+    pub fps: i32,
+    /// Try enabling V-Sync on GPU
+    pub vsync: bool,
+    /// Try enabling 4x MSAA
+    pub msaa: bool,
+    /// Support HighDPI
+    pub high_dpi: bool,
+    /// Allow the window to be resized
+    pub resizable: bool,
+    /// Key closing the window, `KeyboardKey::Null` to disable it
+    #[cfg_attr(feature = "config", serde(with = "code"))]
+    pub exit_key: KeyboardKey,
+    /// Initialise the audio device
+    pub audio: bool,
+    /// Start with the master volume down
+    pub mute: bool,
+    /// Lowest level of raylib and rscenes messages shown
+    #[cfg_attr(feature = "config", serde(with = "level"))]
+    pub log_level: TraceLogLevel,
+    /// Also log into this rotating file, relative to the XDG state directory
+    pub log_file: Option<PathBuf>,
+    /// Directory crash reports are written into, relative to the XDG state directory, like
+    /// `my-game/crashes`, none (the default) to disable them
+    pub crash_dir: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            size: (0, 0),
+            fullscreen: false,
+            monitor: None,
+            min_size: None,
+            max_size: None,
+            fps: 60,
+            vsync: false,
+            msaa: false,
+            high_dpi: false,
+            resizable: false,
+            exit_key: KeyboardKey::Escape,
+            audio: false,
+            mute: false,
+            log_level: TraceLogLevel::Info,
+            log_file: None,
+            crash_dir: None,
+        }
+    }
+}
+
+impl Config {
+    /// Flags to set before the window is created
+    fn flags(&self) -> usize {
+        [
+            (self.fullscreen, ConfigFlags::FullscreenMode),
+            (self.vsync, ConfigFlags::VsyncHint),
+            (self.msaa, ConfigFlags::Msaa4xHint),
+            (self.high_dpi, ConfigFlags::WindowHighdpi),
+            (self.resizable, ConfigFlags::WindowResizable),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .fold(0, |flags, (_, flag)| flags | flag as usize)
+    }
+
+    /// Send raylib messages through the `log` crate, installing a [`Logger`] unless the game
+    /// set its own
+    pub(crate) fn init_logging(&self, connector: impl Rcore) {
+        let level = self.log_level.to_level_filter();
+        let mut file_error = None;
+        let logger = match &self.log_file {
+            Some(path) => Logger::new(level).with_file(path).unwrap_or_else(|err| {
+                file_error = Some(err);
+                Logger::new(level)
+            }),
+            None => Logger::new(level),
+        };
+        // Fails when the game installed a logger already, which is fine
+        let _ = logger.init();
+        if let Some(err) = file_error {
+            log::warn!("opening log file: {}", report(&err));
+        }
+        connector.forward_trace_log();
+    }
+
+    /// Settings raylib reads when creating the window
+    pub(crate) fn apply_before_init(&self, connector: impl Rcore) {
+        connector.set_trace_log_level(self.log_level);
+        let flags = self.flags();
+        if flags != 0 {
+            connector.set_config_flags(flags);
+        }
+    }
+
+    /// Settings requiring the window, and the audio device
+    pub(crate) fn apply_after_init(&self, connector: impl Rcore + Raudio) {
+        if let Some(monitor) = self.monitor {
+            connector.set_window_monitor(monitor);
+        }
+        connector.set_target_fps(self.fps);
+        if let Some((width, height)) = self.min_size {
+            connector.set_window_min_size(width, height);
+        }
+        if let Some((width, height)) = self.max_size {
+            connector.set_window_max_size(width, height);
+        }
+        connector.set_exit_key(self.exit_key);
+        if self.audio {
+            connector.init_audio_device();
+            if self.mute {
+                connector.set_master_volume(0.0);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl Config {
+    /// Load from a JSON file when its extension is `.json`, or a TOML file otherwise
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        if path.extension().is_some_and(|ext| ext == "json") {
+            Ok(serde_json::from_str(&content)?)
+        } else {
+            toml::from_str(&content).map_err(|err| Error::Serialization(Box::new(err)))
+        }
+    }
+
+    /// Override with the `RSCENES_*` environment variables set, named after the fields
+    ///
+    /// Sizes are given as `WIDTHxHEIGHT`, flags as `true`/`false` or `1`/`0`, the exit key by
+    /// its raylib code and the log level by name.
+    pub fn with_env(mut self) -> Result<Self, Error> {
+        self.override_with(|name| env::var(format!("{}{}", ENV_PREFIX, name)).ok())?;
+        Ok(self)
+    }
+
+    fn override_with(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), Error> {
+        let parse = |name: &str, value: &str| -> Error {
+            Error::Serialization(format!("invalid {}{}: {}", ENV_PREFIX, name, value).into())
+        };
+        let size = |name: &str, value: &str| -> Result<(i32, i32), Error> {
+            parse_size(value).ok_or_else(|| parse(name, value))
+        };
+        let flag = |name: &str, value: &str| -> Result<bool, Error> {
+            match value.to_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => Ok(true),
+                "0" | "false" | "no" | "off" => Ok(false),
+                _ => Err(parse(name, value)),
+            }
+        };
+
+        if let Some(value) = var("TITLE") {
+            self.title = value;
+        }
+        if let Some(value) = var("SIZE") {
+            self.size = size("SIZE", &value)?;
+        }
+        if let Some(value) = var("FULLSCREEN") {
+            self.fullscreen = flag("FULLSCREEN", &value)?;
+        }
+        if let Some(value) = var("MONITOR") {
+            self.monitor = Some(value.parse().map_err(|_| parse("MONITOR", &value))?);
+        }
+        if let Some(value) = var("MIN_SIZE") {
+            self.min_size = Some(size("MIN_SIZE", &value)?);
+        }
+        if let Some(value) = var("MAX_SIZE") {
+            self.max_size = Some(size("MAX_SIZE", &value)?);
+        }
+        if let Some(value) = var("FPS") {
+            self.fps = value.parse().map_err(|_| parse("FPS", &value))?;
+        }
+        if let Some(value) = var("VSYNC") {
+            self.vsync = flag("VSYNC", &value)?;
+        }
+        if let Some(value) = var("MSAA") {
+            self.msaa = flag("MSAA", &value)?;
+        }
+        if let Some(value) = var("HIGH_DPI") {
+            self.high_dpi = flag("HIGH_DPI", &value)?;
+        }
+        if let Some(value) = var("RESIZABLE") {
+            self.resizable = flag("RESIZABLE", &value)?;
+        }
+        if let Some(value) = var("EXIT_KEY") {
+            self.exit_key = value
+                .parse::<i64>()
+                .ok()
+                .and_then(num_traits::FromPrimitive::from_i64)
+                .ok_or_else(|| parse("EXIT_KEY", &value))?;
+        }
+        if let Some(value) = var("AUDIO") {
+            self.audio = flag("AUDIO", &value)?;
+        }
+        if let Some(value) = var("MUTE") {
+            self.mute = flag("MUTE", &value)?;
+        }
+        if let Some(value) = var("LOG_LEVEL") {
+            self.log_level = level::parse(&value).ok_or_else(|| parse("LOG_LEVEL", &value))?;
+        }
+        if let Some(value) = var("LOG_FILE") {
+            self.log_file = Some(value.into());
+        }
+        if let Some(value) = var("CRASH_DIR") {
+            self.crash_dir = Some(value.into());
+        }
+        Ok(())
+    }
+}
+
+/// Parse `WIDTHxHEIGHT`
+pub(crate) fn parse_size(value: &str) -> Option<(i32, i32)> {
+    let (width, height) = value.split_once(['x', 'X'])?;
+    Some((width.trim().parse().ok()?, height.trim().parse().ok()?))
+}
+
+/// Trace levels are given by name
+pub(crate) mod level {
+    use rscenes_raylib_connector::assets::TraceLogLevel;
+
+    const NAMES: [(&str, TraceLogLevel); 8] = [
+        ("all", TraceLogLevel::All),
+        ("trace", TraceLogLevel::Trace),
+        ("debug", TraceLogLevel::Debug),
+        ("info", TraceLogLevel::Info),
+        ("warning", TraceLogLevel::Warning),
+        ("error", TraceLogLevel::Error),
+        ("fatal", TraceLogLevel::Fatal),
+        ("none", TraceLogLevel::None),
+    ];
+
+    pub fn parse(name: &str) -> Option<TraceLogLevel> {
+        let name = name.to_lowercase();
+        NAMES
+            .iter()
+            .find(|(candidate, _)| *candidate == name)
+            .map(|(_, level)| *level)
+    }
+
+    #[cfg(feature = "config")]
+    pub fn name(level: TraceLogLevel) -> &'static str {
+        NAMES
+            .iter()
+            .find(|(_, candidate)| *candidate == level)
+            .map(|(name, _)| *name)
+            .unwrap_or("info")
+    }
+
+    #[cfg(feature = "config")]
+    pub fn serialize<S: serde::Serializer>(
+        level: &TraceLogLevel,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(name(*level))
+    }
+
+    #[cfg(feature = "config")]
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TraceLogLevel, D::Error> {
+        use serde::{de, Deserialize};
+        let name = String::deserialize(deserializer)?;
+        parse(&name).ok_or_else(|| de::Error::custom(format!("unknown log level {name}")))
+    }
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "config")]
+    use crate::error::report;
+
+    #[test]
+    fn it_should_combine_window_flags() {
+        let config = Config {
+            vsync: true,
+            resizable: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.flags(),
+            ConfigFlags::VsyncHint as usize | ConfigFlags::WindowResizable as usize
+        );
+        assert_eq!(Config::default().flags(), 0);
+    }
+
+    #[test]
+    fn it_should_parse_sizes() {
+        assert_eq!(parse_size("800x600"), Some((800, 600)));
+        assert_eq!(parse_size("1024X768"), Some((1024, 768)));
+        assert_eq!(parse_size("800"), None);
+        assert_eq!(parse_size("axb"), None);
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn it_should_name_log_levels() {
+        assert_eq!(level::parse("Warning"), Some(TraceLogLevel::Warning));
+        assert_eq!(level::parse("loud"), None);
+        assert_eq!(level::name(TraceLogLevel::Error), "error");
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn it_should_load_partial_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            title = "Nonogram"
+            size = [800, 600]
+            min_size = [640, 480]
+            fps = 30
+            resizable = true
+            exit_key = 0
+            log_level = "error"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                title: "Nonogram".to_owned(),
+                size: (800, 600),
+                min_size: Some((640, 480)),
+                fps: 30,
+                resizable: true,
+                exit_key: KeyboardKey::Null,
+                log_level: TraceLogLevel::Error,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn it_should_load_json_files() {
+        let path = env::temp_dir().join(format!("rscenes-config-{}.json", std::process::id()));
+        fs::write(&path, r#"{"title": "Balls", "audio": true}"#).unwrap();
+        let config = Config::load(&path);
+        fs::remove_file(&path).unwrap();
+        let config = config.unwrap();
+        assert_eq!(config.title, "Balls");
+        assert!(config.audio);
+        assert_eq!(config.fps, 60);
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn it_should_override_with_variables() {
+        let vars = [
+            ("SIZE", "1024x768"),
+            ("FULLSCREEN", "yes"),
+            ("MONITOR", "1"),
+            ("MAX_SIZE", "1920x1080"),
+            ("FPS", "144"),
+            ("VSYNC", "on"),
+            ("EXIT_KEY", "81"),
+            ("LOG_LEVEL", "none"),
+            ("LOG_FILE", "game/game.log"),
+        ];
+        let mut config = Config::default();
+        config
+            .override_with(|name| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| value.to_string())
+            })
+            .unwrap();
+        assert_eq!(config.size, (1024, 768));
+        assert!(config.fullscreen);
+        assert_eq!(config.monitor, Some(1));
+        assert_eq!(config.max_size, Some((1920, 1080)));
+        assert_eq!(config.fps, 144);
+        assert!(config.vsync);
+        assert_eq!(config.exit_key, KeyboardKey::Q);
+        assert_eq!(config.log_level, TraceLogLevel::None);
+        assert_eq!(config.log_file, Some(PathBuf::from("game/game.log")));
+        assert_eq!(config.title, "");
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn it_should_reject_invalid_variables() {
+        let mut config = Config::default();
+        let res = config.override_with(|name| (name == "FPS").then(|| "fast".to_owned()));
+        assert_eq!(
+            report(&res.unwrap_err()),
+            "serialization error, caused by: invalid RSCENES_FPS: fast"
+        );
+    }
+}
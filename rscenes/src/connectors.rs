@@ -1,35 +1,75 @@
+use crate::{
+    cli::{self, Options},
+    timestep,
+};
 use rscenes_raylib_connector::interface::*;
 
 #[derive(Clone, Copy, Debug)]
 pub struct PlainConnector;
 
+#[cfg(not(feature = "headless"))]
 impl Rcore for PlainConnector {}
 impl Rgestures for PlainConnector {}
 impl Rcamera for PlainConnector {}
 impl RshapesCollisions for PlainConnector {}
 impl RmodelsCollisions for PlainConnector {}
+#[cfg(not(feature = "headless"))]
 impl Rtextures for PlainConnector {}
+#[cfg(not(feature = "headless"))]
 impl Rtext for PlainConnector {}
+#[cfg(not(feature = "headless"))]
 impl Raudio for PlainConnector {}
 
+impl PlainConnector {
+    /// Command-line options the game was started with
+    pub fn get_options(&self) -> Options {
+        cli::get_options()
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct Connector2D;
 
+#[cfg(not(feature = "headless"))]
 impl Rcore for Connector2D {}
 impl Rgestures for Connector2D {}
+#[cfg(not(feature = "headless"))]
 impl Rshapes for Connector2D {}
 impl RshapesCollisions for Connector2D {}
+#[cfg(not(feature = "headless"))]
 impl Rtextures for Connector2D {}
+#[cfg(not(feature = "headless"))]
 impl Rtext for Connector2D {}
+#[cfg(not(feature = "headless"))]
 impl Raudio for Connector2D {}
 
+impl Connector2D {
+    /// How far the simulation is between the last fixed update and the next one, from 0.0 to
+    /// 1.0 (always 1.0 when fixed timestep is off)
+    pub fn get_interpolation_alpha(&self) -> f32 {
+        timestep::get_interpolation_alpha()
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct Connector3D;
 
+impl Connector3D {
+    /// How far the simulation is between the last fixed update and the next one, from 0.0 to
+    /// 1.0 (always 1.0 when fixed timestep is off)
+    pub fn get_interpolation_alpha(&self) -> f32 {
+        timestep::get_interpolation_alpha()
+    }
+}
+
+#[cfg(not(feature = "headless"))]
 impl Rcore for Connector3D {}
 impl Rgestures for Connector3D {}
 impl Rcamera for Connector3D {}
+#[cfg(not(feature = "headless"))]
 impl Rtextures for Connector3D {}
+#[cfg(not(feature = "headless"))]
 impl Rmodels for Connector3D {}
 impl RmodelsCollisions for Connector3D {}
+#[cfg(not(feature = "headless"))]
 impl Raudio for Connector3D {}
@@ -0,0 +1,291 @@
+use crate::{
+    connectors::*,
+    error::Error,
+    logger::{timestamp, xdg_state_home},
+    scene::Scene,
+    state::State,
+};
+use rscenes_raylib_connector::{assets::*, interface::*};
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    cell::RefCell,
+    error::Error as StdError,
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::Once,
+    time::SystemTime,
+};
+
+const MARGIN: i32 = 24;
+const TITLE_SIZE: i32 = 30;
+const FONT_SIZE: i32 = 20;
+const LINE_SPACING: i32 = 6;
+const BACKGROUND: Color = Color {
+    r: 32,
+    g: 16,
+    b: 16,
+    a: 255,
+};
+
+thread_local! {
+    /// Location and backtrace of the last panic, recorded by the panic hook
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// What the manager does when a scene fails to set up or load, returns an error from an
+/// update, or panics
+///
+/// Errors from the other callbacks are just logged. Crash reports are only written when the
+/// game opts in by setting [`Config::crash_dir`](crate::prelude::Config::crash_dir).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Exit the game, errors returned by updates are only logged, like the other callbacks'
+    #[default]
+    Abort,
+    /// Drop the failing scene, along with the ones above it, and get back to the one below it,
+    /// exiting when there's none
+    Skip,
+    /// Push a scene showing the error and the scene stack, letting the player go back to the
+    /// scene below the failing one, or quit
+    CrashScreen,
+}
+
+/// Scene failure, as shown on the crash screen and written into the crash report
+#[derive(Debug)]
+pub(crate) struct Crash {
+    /// What the scene was doing, like `updating Gameplay #2 scene`
+    context: String,
+    /// The error followed by its sources
+    chain: Vec<String>,
+    /// Where the scene panicked, along with the backtrace
+    panic: Option<String>,
+    /// Scene labels, from the top down
+    stack: Vec<String>,
+    time: SystemTime,
+}
+
+impl Crash {
+    pub(crate) fn new(context: String, err: &Error, stack: Vec<String>) -> Self {
+        let mut chain = vec![err.to_string()];
+        let mut source = err.source();
+        while let Some(err) = source {
+            chain.push(err.to_string());
+            source = err.source();
+        }
+        let panic = match err {
+            Error::Panic(_) => LAST_PANIC.with(|last| last.borrow_mut().take()),
+            _ => None,
+        };
+        Self {
+            context,
+            chain,
+            panic,
+            stack,
+            time: SystemTime::now(),
+        }
+    }
+
+    fn report(&self) -> String {
+        let mut res = format!("Crash report, {} UTC\n\n", timestamp(self.time));
+        res.push_str(&format!("{}:\n", self.context));
+        for line in self.chain.iter() {
+            res.push_str(&format!("  {}\n", line));
+        }
+        if let Some(panic) = &self.panic {
+            res.push_str(&format!("\n{}\n", panic.trim_end()));
+        }
+        res.push_str("\nScene stack, from the top:\n");
+        for label in self.stack.iter() {
+            res.push_str(&format!("  {}\n", label));
+        }
+        res
+    }
+
+    /// Write the report into `dir`, relative paths are taken from the XDG state directory
+    pub(crate) fn save(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let dir = xdg_state_home().join(dir);
+        fs::create_dir_all(&dir)?;
+        let time = timestamp(self.time);
+        let name = format!("crash-{}.txt", time.replace([' ', ':', '.'], "-"));
+        let path = dir.join(name);
+        fs::write(&path, self.report())?;
+        Ok(path)
+    }
+}
+
+/// Built-in scene pushed over the failing one by [`ErrorPolicy::CrashScreen`]
+#[derive(Debug)]
+pub(crate) struct CrashScene {
+    crash: Crash,
+    report: Option<PathBuf>,
+    /// Scenes from the failing one up to the top of the stack, below the crash screen
+    depth: usize,
+    can_go_back: bool,
+}
+
+impl CrashScene {
+    pub(crate) fn new(crash: Crash, report: Option<PathBuf>, depth: usize) -> Self {
+        // There must be a scene below the failing one to get back to
+        let can_go_back = crash.stack.len() > depth;
+        Self {
+            crash,
+            report,
+            depth,
+            can_go_back,
+        }
+    }
+
+    fn lines(&self) -> Vec<(String, i32, Color)> {
+        let mut lines = vec![
+            ("Something went wrong".to_owned(), TITLE_SIZE, Color::RED),
+            (
+                format!("{}:", self.crash.context),
+                FONT_SIZE,
+                Color::RAYWHITE,
+            ),
+        ];
+        for line in self.crash.chain.iter() {
+            lines.push((format!("  {}", line), FONT_SIZE, Color::ORANGE));
+        }
+        lines.push(("Scene stack:".to_owned(), FONT_SIZE, Color::RAYWHITE));
+        for label in self.crash.stack.iter() {
+            lines.push((format!("  {}", label), FONT_SIZE, Color::LIGHTGRAY));
+        }
+        if let Some(report) = &self.report {
+            let line = format!("Report saved into {}", report.display());
+            lines.push((line, FONT_SIZE, Color::LIGHTGRAY));
+        }
+        let actions = if self.can_go_back {
+            "[B] back    [Q] quit"
+        } else {
+            "[Q] quit"
+        };
+        lines.push((actions.to_owned(), FONT_SIZE, Color::GOLD));
+        lines
+    }
+}
+
+impl Scene for CrashScene {
+    fn name(&self) -> Option<&str> {
+        Some("crash")
+    }
+
+    fn on_update(&mut self, connector: PlainConnector, _: f32) -> Result<State, Error> {
+        if self.can_go_back && connector.is_key_pressed(KeyboardKey::B) {
+            // Along with the failing scene and the ones above it
+            return Ok(State::Prev(self.depth + 1));
+        }
+        if connector.is_key_pressed(KeyboardKey::Q) {
+            return Ok(State::Quit);
+        }
+        Ok(State::Keep)
+    }
+
+    fn draw_2d(&self, connector: Connector2D) -> Result<(), Error> {
+        connector.clear_background(BACKGROUND);
+        Ok(())
+    }
+
+    fn draw_hud(&self, connector: Connector2D) -> Result<(), Error> {
+        let mut y = MARGIN;
+        for (line, size, color) in self.lines() {
+            connector.draw_text(line, MARGIN, y, size, color);
+            y += size + LINE_SPACING;
+        }
+        Ok(())
+    }
+}
+
+/// Record where panics happen, for the crash reports, before handing them to the current hook
+pub(crate) fn install_panic_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let location = match info.location() {
+                Some(location) => location.to_string(),
+                None => "unknown location".to_owned(),
+            };
+            let details = format!("panicked at {}\n{}", location, Backtrace::force_capture());
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(details));
+            previous(info);
+        }));
+    });
+}
+
+/// Run a scene callback, turning panics into errors
+pub(crate) fn catch<T>(f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(Error::Panic(panic_message(payload.as_ref()))))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "unknown panic".to_owned(),
+        },
+    }
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rscenes_raylib_connector::RaylibError;
+
+    #[test]
+    fn it_should_turn_panics_into_errors() {
+        install_panic_hook();
+        let res: Result<(), Error> = catch(|| panic!("out of {}", "cheese"));
+        let err = res.unwrap_err();
+        assert_eq!(err.to_string(), "panicked: out of cheese");
+
+        let crash = Crash::new("updating Game #1 scene".to_owned(), &err, vec![]);
+        assert!(crash
+            .panic
+            .unwrap()
+            .starts_with("panicked at rscenes/src/crash.rs:"));
+        assert!(catch(|| Ok(3)).is_ok());
+    }
+
+    #[test]
+    fn it_should_report_the_error_chain_and_the_stack() {
+        let err: Error = RaylibError::FileNotFound("foo.png".to_owned()).into();
+        let stack = vec!["Game #2".to_owned(), "Menu #1".to_owned()];
+        let crash = Crash::new("loading Game #2 scene".to_owned(), &err, stack);
+        let report = crash.report();
+        assert!(report.starts_with("Crash report, "));
+        assert!(report.ends_with(
+            " UTC\n\n\
+             loading Game #2 scene:\n  \
+               asset error\n  \
+               file foo.png not found\n\n\
+             Scene stack, from the top:\n  \
+               Game #2\n  \
+               Menu #1\n"
+        ));
+    }
+
+    #[test]
+    fn it_should_offer_going_back_to_a_scene_below() {
+        let err: Error = "boom".into();
+        let alone = CrashScene::new(
+            Crash::new("".to_owned(), &err, vec!["Game #1".to_owned()]),
+            None,
+            1,
+        );
+        assert_eq!(alone.lines().last().unwrap().0, "[Q] quit");
+        let stack = vec!["Game #2".to_owned(), "Menu #1".to_owned()];
+        let above = CrashScene::new(Crash::new("".to_owned(), &err, stack.clone()), None, 1);
+        assert_eq!(above.lines().last().unwrap().0, "[B] back    [Q] quit");
+        let frozen = CrashScene::new(Crash::new("".to_owned(), &err, stack), None, 2);
+        assert_eq!(frozen.lines().last().unwrap().0, "[Q] quit");
+    }
+}
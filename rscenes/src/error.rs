@@ -0,0 +1,143 @@
+use rscenes_raylib_connector::RaylibError;
+use std::{error::Error as StdError, fmt, io};
+
+type BoxedError = Box<dyn StdError + Send + Sync + 'static>;
+
+/// Errors raised by scenes and the scene manager
+#[derive(Debug)]
+pub enum Error {
+    /// Raylib failed to load or handle an asset
+    Asset(RaylibError),
+    /// I/O failure
+    Io(io::Error),
+    /// Data couldn't be serialised or deserialised
+    Serialization(BoxedError),
+    /// The manager or a scene reached an invalid state
+    InvalidState(String),
+    /// Command-line arguments couldn't be parsed
+    Usage(String),
+    /// A scene callback panicked, with the panic message
+    Panic(String),
+    /// Application defined error
+    User(BoxedError),
+}
+
+impl Error {
+    /// Wrap any error raised by the application
+    pub fn user(err: impl Into<BoxedError>) -> Self {
+        Self::User(err.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Asset(_) => write!(f, "asset error"),
+            Self::Io(_) => write!(f, "I/O error"),
+            Self::Serialization(_) => write!(f, "serialization error"),
+            Self::InvalidState(msg) => write!(f, "invalid state: {msg}"),
+            Self::Usage(msg) => write!(f, "usage error: {msg}"),
+            Self::Panic(msg) => write!(f, "panicked: {msg}"),
+            Self::User(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Asset(err) => Some(err),
+            Self::Io(err) => Some(err),
+            Self::Serialization(err) => Some(err.as_ref()),
+            Self::InvalidState(_) | Self::Usage(_) | Self::Panic(_) => None,
+            Self::User(err) => err.source(),
+        }
+    }
+}
+
+impl From<RaylibError> for Error {
+    fn from(value: RaylibError) -> Self {
+        Self::Asset(value)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+#[cfg(any(feature = "config", feature = "storage"))]
+impl From<serde_json::Error> for Error {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Serialization(Box::new(value))
+    }
+}
+
+impl From<String> for Error {
+    fn from(value: String) -> Self {
+        Self::User(value.into())
+    }
+}
+
+impl From<&str> for Error {
+    fn from(value: &str) -> Self {
+        Self::User(value.into())
+    }
+}
+
+/// Render the error followed by its chain of sources
+pub(crate) fn report(err: &dyn StdError) -> String {
+    let mut res = err.to_string();
+    let mut source = err.source();
+    while let Some(err) = source {
+        res = format!("{res}, caused by: {err}");
+        source = err.source();
+    }
+    res
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_io_errors() {
+        let err: Error = io::Error::new(io::ErrorKind::NotFound, "missing").into();
+        assert!(matches!(err, Error::Io(_)));
+        assert_eq!(err.to_string(), "I/O error");
+        assert_eq!(err.source().unwrap().to_string(), "missing");
+    }
+
+    #[test]
+    fn it_should_convert_raylib_errors() {
+        let err: Error = RaylibError::FileNotFound("foo.png".to_owned()).into();
+        assert!(matches!(err, Error::Asset(_)));
+        assert_eq!(
+            report(&err),
+            "asset error, caused by: file foo.png not found"
+        );
+    }
+
+    #[test]
+    fn it_should_convert_strings_into_user_errors() {
+        let err: Error = "boom".into();
+        assert!(matches!(err, Error::User(_)));
+        assert_eq!(err.to_string(), "boom");
+    }
+
+    #[test]
+    fn it_should_report_source_chain() {
+        let raw = vec![0xff_u8];
+        let err: Error = RaylibError::Utf8(std::str::from_utf8(&raw).unwrap_err()).into();
+        assert_eq!(
+            report(&err),
+            "asset error, caused by: invalid UTF-8 string, \
+             caused by: invalid utf-8 sequence of 1 bytes from index 0"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{connectors::PlainConnector, setup};
+use crate::{connectors::PlainConnector, error::Error, setup};
 use rscenes_raylib_connector::{assets::*, interface::*};
 
 //const MASK: usize = ((ConfigFlags::InterlacedHint as usize) << 1) - 1;
@@ -15,7 +15,7 @@ const FAKE_FLAGS: usize = MASK & (POSITIVE_FLAGS ^ NEGATIVE_FLAGS);
 
 static mut OTHER_FLAGS: Option<usize> = None;
 
-pub fn start_fullscreen(other_flags: usize) -> Box<dyn Fn(PlainConnector) -> Result<(), String>> {
+pub fn start_fullscreen(other_flags: usize) -> Box<dyn Fn(PlainConnector) -> Result<(), Error>> {
     if other_flags != 0 {
         unsafe {
             OTHER_FLAGS = Some(other_flags);
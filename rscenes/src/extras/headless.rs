@@ -0,0 +1,951 @@
+use crate::connectors::*;
+use rscenes_raylib_connector::{assets::*, headless, interface::*};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+thread_local! {
+    static STATE: RefCell<HeadlessState> = RefCell::new(HeadlessState::default());
+}
+
+/// Drawing call recorded by the headless backend
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawCall {
+    /// Name of the connector method called
+    pub name: &'static str,
+    /// Debug representation of the arguments
+    pub args: String,
+}
+
+/// Headless backend control
+///
+/// With the `headless` feature enabled, `reset()` switches the connectors of the current
+/// thread away from Raylib: they read scripted window, input and time state from here and
+/// record every drawing call instead, while resources are faked. Other threads, like the
+/// game's main one, keep using Raylib. The state is thread-local, so each test has its own.
+#[derive(Clone, Copy, Debug)]
+pub struct Headless;
+
+impl Headless {
+    /// Reset the headless state to its defaults, going headless on the current thread
+    pub fn reset() {
+        STATE.with(|state| *state.borrow_mut() = HeadlessState::default());
+        headless::set_enabled(true);
+    }
+
+    /// Go back to Raylib on the current thread
+    pub fn disable() {
+        headless::set_enabled(false);
+    }
+
+    /// Whether the connectors are headless on the current thread
+    pub fn is_enabled() -> bool {
+        headless::is_enabled()
+    }
+
+    /// Set the window (screen and render) size
+    pub fn set_render_size(width: i32, height: i32) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.render_size = (width, height);
+            state.resized = true;
+        });
+    }
+
+    /// Resize the window as the user would: the new size is reported as a resize in the next
+    /// frame
+    pub fn resize_window(width: i32, height: i32) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.render_size = (width, height);
+            state.resize_pending = true;
+        });
+    }
+
+    /// Set the time the frames take
+    pub fn set_frame_time(dt: f32) {
+        STATE.with(|state| state.borrow_mut().frame_time = dt);
+    }
+
+    /// Set whether the window is focused
+    pub fn set_focused(focused: bool) {
+        STATE.with(|state| state.borrow_mut().focused = focused);
+    }
+
+    /// Make window_should_close() return true after some frames
+    pub fn close_after(frames: u64) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.close_after = Some(state.frame + frames);
+        });
+    }
+
+    /// Current frame count
+    pub fn frame() -> u64 {
+        STATE.with(|state| state.borrow().frame)
+    }
+
+    /// Finish the current frame: input edges are cleared and time advances
+    pub fn next_frame() {
+        STATE.with(|state| state.borrow_mut().next_frame());
+    }
+
+    /// Press a key down (it keeps down until released)
+    pub fn press_key(key: KeyboardKey) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.keys.insert(key);
+            state.key_queue.push_back(key);
+        });
+    }
+
+    /// Release a key
+    pub fn release_key(key: KeyboardKey) {
+        STATE.with(|state| {
+            state.borrow_mut().keys.remove(&key);
+        });
+    }
+
+    /// Simulate the OS repeating a held key on this frame
+    pub fn repeat_key(key: KeyboardKey) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.keys.insert(key);
+            state.repeated_keys.insert(key);
+        });
+    }
+
+    /// Simulate a key that was down on the last frame and got released on this one
+    pub fn tap_key(key: KeyboardKey) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.prev_keys.insert(key);
+            state.keys.remove(&key);
+        });
+    }
+
+    /// Queue a typed character
+    pub fn type_char(c: char) {
+        STATE.with(|state| state.borrow_mut().char_queue.push_back(c));
+    }
+
+    /// Press a mouse button down (it keeps down until released)
+    pub fn press_mouse_button(button: MouseButton) {
+        STATE.with(|state| {
+            state.borrow_mut().buttons.insert(button);
+        });
+    }
+
+    /// Release a mouse button
+    pub fn release_mouse_button(button: MouseButton) {
+        STATE.with(|state| {
+            state.borrow_mut().buttons.remove(&button);
+        });
+    }
+
+    /// Simulate a mouse button that was down on the last frame and got released on this one
+    pub fn click(button: MouseButton) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.prev_buttons.insert(button);
+            state.buttons.remove(&button);
+        });
+    }
+
+    /// Move mouse
+    pub fn set_mouse_position(position: Vector2) {
+        STATE.with(|state| state.borrow_mut().mouse_position = position);
+    }
+
+    /// Set mouse wheel movement for the current frame
+    pub fn set_mouse_wheel_move(wheel: Vector2) {
+        STATE.with(|state| state.borrow_mut().mouse_wheel = wheel);
+    }
+
+    /// Press a gamepad button down (it keeps down until released)
+    pub fn press_gamepad_button(gamepad: i32, button: GamepadButton) {
+        STATE.with(|state| {
+            state.borrow_mut().gamepad_buttons.insert((gamepad, button));
+        });
+    }
+
+    /// Release a gamepad button
+    pub fn release_gamepad_button(gamepad: i32, button: GamepadButton) {
+        STATE.with(|state| {
+            state
+                .borrow_mut()
+                .gamepad_buttons
+                .remove(&(gamepad, button));
+        });
+    }
+
+    /// Move a gamepad axis (it keeps still until moved again)
+    pub fn set_gamepad_axis(gamepad: i32, axis: GamepadAxis, value: f32) {
+        STATE.with(|state| {
+            state
+                .borrow_mut()
+                .gamepad_axes
+                .insert((gamepad, axis), value);
+        });
+    }
+
+    /// Drawing calls recorded so far
+    pub fn draw_calls() -> Vec<DrawCall> {
+        STATE.with(|state| state.borrow().draw_calls.clone())
+    }
+
+    /// Drawing calls recorded so far, clearing the record
+    pub fn take_draw_calls() -> Vec<DrawCall> {
+        STATE.with(|state| state.borrow_mut().draw_calls.drain(..).collect())
+    }
+
+    fn record(name: &'static str, args: String) {
+        STATE.with(|state| state.borrow_mut().draw_calls.push(DrawCall { name, args }));
+    }
+
+    fn with<R>(f: impl FnOnce(&mut HeadlessState) -> R) -> R {
+        STATE.with(|state| f(&mut state.borrow_mut()))
+    }
+}
+
+/// Raylib itself, called by the connectors while not headless
+#[derive(Clone, Copy, Debug)]
+struct Raylib;
+
+impl Rcore for Raylib {}
+impl Rshapes for Raylib {}
+impl Rtextures for Raylib {}
+impl Rtext for Raylib {}
+impl Rmodels for Raylib {}
+impl Raudio for Raylib {}
+
+#[derive(Debug)]
+struct HeadlessState {
+    frame: u64,
+    frame_time: f32,
+    time: f64,
+    target_fps: i32,
+    close_after: Option<u64>,
+    closed: bool,
+    render_size: (i32, i32),
+    window_state: usize,
+    monitor: i32,
+    focused: bool,
+    resized: bool,
+    resize_pending: bool,
+    audio_ready: bool,
+    master_volume: f32,
+    keys: HashSet<KeyboardKey>,
+    prev_keys: HashSet<KeyboardKey>,
+    repeated_keys: HashSet<KeyboardKey>,
+    key_queue: VecDeque<KeyboardKey>,
+    char_queue: VecDeque<char>,
+    buttons: HashSet<MouseButton>,
+    prev_buttons: HashSet<MouseButton>,
+    mouse_position: Vector2,
+    prev_mouse_position: Vector2,
+    mouse_wheel: Vector2,
+    gamepad_buttons: HashSet<(i32, GamepadButton)>,
+    prev_gamepad_buttons: HashSet<(i32, GamepadButton)>,
+    gamepad_axes: HashMap<(i32, GamepadAxis), f32>,
+    draw_calls: Vec<DrawCall>,
+}
+
+impl HeadlessState {
+    fn next_frame(&mut self) {
+        self.frame += 1;
+        self.time += self.frame_time as f64;
+        self.resized = self.resize_pending;
+        self.resize_pending = false;
+        self.prev_keys = self.keys.clone();
+        self.prev_buttons = self.buttons.clone();
+        self.prev_gamepad_buttons = self.gamepad_buttons.clone();
+        self.prev_mouse_position = self.mouse_position;
+        self.mouse_wheel = Vector2::ZERO;
+        self.repeated_keys.clear();
+        self.key_queue.clear();
+        self.char_queue.clear();
+    }
+
+    fn is_fullscreen(&self) -> bool {
+        self.window_state & ConfigFlags::FullscreenMode as usize != 0
+    }
+}
+
+impl Default for HeadlessState {
+    fn default() -> Self {
+        Self {
+            frame: 0,
+            frame_time: 1.0 / 60.0,
+            time: 0.0,
+            target_fps: 60,
+            close_after: None,
+            closed: false,
+            render_size: (800, 600),
+            window_state: 0,
+            monitor: 0,
+            focused: true,
+            resized: false,
+            resize_pending: false,
+            audio_ready: false,
+            master_volume: 1.0,
+            keys: HashSet::new(),
+            prev_keys: HashSet::new(),
+            repeated_keys: HashSet::new(),
+            key_queue: VecDeque::new(),
+            char_queue: VecDeque::new(),
+            buttons: HashSet::new(),
+            prev_buttons: HashSet::new(),
+            mouse_position: Vector2::ZERO,
+            prev_mouse_position: Vector2::ZERO,
+            mouse_wheel: Vector2::ZERO,
+            gamepad_buttons: HashSet::new(),
+            prev_gamepad_buttons: HashSet::new(),
+            gamepad_axes: HashMap::new(),
+            draw_calls: vec![],
+        }
+    }
+}
+
+/// Run the headless body while headless, calling Raylib otherwise
+macro_rules! headless {
+    ($trait:ident; $($(#[$attr:meta])* fn $name:ident(&$self:ident $(, $arg:ident: $tpe:ty)* $(,)?) $(-> $ret:ty)? $body:block)*) => {
+        $(
+            $(#[$attr])*
+            fn $name(&$self $(, $arg: $tpe)*) $(-> $ret)? {
+                if Headless::is_enabled() $body else { $trait::$name(&Raylib $(, $arg)*) }
+            }
+        )*
+    };
+}
+
+/// Record drawing calls instead of calling Raylib while headless
+macro_rules! record {
+    ($trait:ident; $(fn $name:ident(&self $(, $arg:ident: $tpe:ty)*);)*) => {
+        headless! {
+            $trait;
+            $(
+                fn $name(&self $(, $arg: $tpe)*) {
+                    Headless::record(stringify!($name), format!("{:?}", ($(&$arg,)*)));
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! headless_rcore {
+    () => {
+        headless! {
+            Rcore;
+            fn init_window(&self, width: i32, height: i32, title: impl Display) {
+                Headless::with(|state| state.render_size = (width, height));
+            }
+
+            fn close_window(&self) {
+                Headless::with(|state| state.closed = true);
+            }
+
+            fn window_should_close(&self) -> bool {
+                Headless::with(|state| {
+                    state.closed || state.close_after.is_some_and(|last| state.frame >= last)
+                })
+            }
+
+            fn is_window_fullscreen(&self) -> bool {
+                Headless::with(|state| state.is_fullscreen())
+            }
+
+            fn is_window_focused(&self) -> bool {
+                Headless::with(|state| state.focused)
+            }
+
+            fn is_window_resized(&self) -> bool {
+                Headless::with(|state| state.resized)
+            }
+
+            fn is_window_state(&self, flag: usize) -> bool {
+                Headless::with(|state| state.window_state & flag == flag)
+            }
+
+            fn set_window_state(&self, flag: usize) {
+                Headless::with(|state| state.window_state |= flag);
+            }
+
+            fn clear_window_state(&self, flag: usize) {
+                Headless::with(|state| state.window_state &= !flag);
+            }
+
+            fn toggle_fullscreen(&self) {
+                Headless::with(|state| state.window_state ^= ConfigFlags::FullscreenMode as usize);
+            }
+
+            fn set_window_title(&self, title: impl Display) {}
+
+            fn set_window_min_size(&self, width: i32, height: i32) {}
+
+            fn set_window_max_size(&self, width: i32, height: i32) {}
+
+            fn set_window_size(&self, width: i32, height: i32) {
+                Headless::set_render_size(width, height);
+            }
+
+            fn get_screen_width(&self) -> i32 {
+                Headless::with(|state| state.render_size.0)
+            }
+
+            fn get_screen_height(&self) -> i32 {
+                Headless::with(|state| state.render_size.1)
+            }
+
+            fn get_screen_rec(&self) -> Rectangle {
+                self.get_render_rec()
+            }
+
+            fn get_render_width(&self) -> i32 {
+                Headless::with(|state| state.render_size.0)
+            }
+
+            fn get_render_height(&self) -> i32 {
+                Headless::with(|state| state.render_size.1)
+            }
+
+            fn get_render_rec(&self) -> Rectangle {
+                let (width, height) = Headless::with(|state| state.render_size);
+                Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: width as f32,
+                    height: height as f32,
+                }
+            }
+
+            fn get_current_monitor(&self) -> i32 {
+                Headless::with(|state| state.monitor)
+            }
+
+            fn set_window_monitor(&self, monitor: i32) {
+                Headless::with(|state| state.monitor = monitor);
+            }
+
+            fn get_monitor_rec(&self, monitor: i32) -> Rectangle {
+                self.get_render_rec()
+            }
+
+            fn get_window_position(&self) -> Vector2 {
+                Vector2::ZERO
+            }
+
+            fn begin_drawing(&self) {}
+
+            fn end_drawing(&self) {
+                Headless::next_frame();
+            }
+        }
+
+        record! {
+            Rcore;
+            fn clear_background(&self, color: Color);
+            fn begin_mode_2d(&self, camera: Camera2D);
+            fn end_mode_2d(&self);
+            fn begin_mode_3d(&self, camera: Camera3D);
+            fn end_mode_3d(&self);
+            fn begin_texture_mode(&self, target: RenderTexture);
+            fn end_texture_mode(&self);
+            fn begin_shader_mode(&self, shader: Shader);
+            fn end_shader_mode(&self);
+            fn begin_blend_mode(&self, mode: BlendMode);
+            fn end_blend_mode(&self);
+            fn begin_scissor_mode(&self, x: i32, y: i32, width: i32, height: i32);
+            fn end_scissor_mode(&self);
+        }
+
+        headless! {
+            Rcore;
+            fn set_target_fps(&self, fps: i32) {
+                Headless::with(|state| state.target_fps = fps);
+            }
+
+            fn get_frame_time(&self) -> f32 {
+                Headless::with(|state| state.frame_time)
+            }
+
+            fn get_time(&self) -> f64 {
+                Headless::with(|state| state.time)
+            }
+
+            fn get_fps(&self) -> i32 {
+                Headless::with(|state| {
+                    if state.frame_time > 0.0 {
+                        (1.0 / state.frame_time).round() as i32
+                    } else {
+                        state.target_fps
+                    }
+                })
+            }
+
+            fn set_config_flags(&self, flags: impl Into<usize>) {
+                Headless::with(|state| state.window_state |= flags.into());
+            }
+
+            fn is_key_pressed(&self, key: KeyboardKey) -> bool {
+                Headless::with(|state| state.keys.contains(&key) && !state.prev_keys.contains(&key))
+            }
+
+            fn is_key_pressed_repeat(&self, key: KeyboardKey) -> bool {
+                Headless::with(|state| state.repeated_keys.contains(&key))
+            }
+
+            fn is_key_down(&self, key: KeyboardKey) -> bool {
+                Headless::with(|state| state.keys.contains(&key))
+            }
+
+            fn is_key_released(&self, key: KeyboardKey) -> bool {
+                Headless::with(|state| !state.keys.contains(&key) && state.prev_keys.contains(&key))
+            }
+
+            fn is_key_up(&self, key: KeyboardKey) -> bool {
+                !self.is_key_down(key)
+            }
+
+            fn get_key_pressed(&self) -> KeyboardKey {
+                Headless::with(|state| state.key_queue.pop_front().unwrap_or(KeyboardKey::Null))
+            }
+
+            fn get_char_pressed(&self) -> String {
+                Headless::with(|state| {
+                    state
+                        .char_queue
+                        .pop_front()
+                        .map(|c| c.to_string())
+                        .unwrap_or_default()
+                })
+            }
+
+            fn set_exit_key(&self, key: KeyboardKey) {}
+
+            fn is_gamepad_available(&self, gamepad: i32) -> bool {
+                Headless::with(|state| {
+                    state.gamepad_buttons.iter().any(|(id, _)| *id == gamepad)
+                        || state.gamepad_axes.keys().any(|(id, _)| *id == gamepad)
+                })
+            }
+
+            fn is_gamepad_button_pressed(&self, gamepad: i32, button: GamepadButton) -> bool {
+                Headless::with(|state| {
+                    state.gamepad_buttons.contains(&(gamepad, button))
+                        && !state.prev_gamepad_buttons.contains(&(gamepad, button))
+                })
+            }
+
+            fn is_gamepad_button_down(&self, gamepad: i32, button: GamepadButton) -> bool {
+                Headless::with(|state| state.gamepad_buttons.contains(&(gamepad, button)))
+            }
+
+            fn is_gamepad_button_released(&self, gamepad: i32, button: GamepadButton) -> bool {
+                Headless::with(|state| {
+                    !state.gamepad_buttons.contains(&(gamepad, button))
+                        && state.prev_gamepad_buttons.contains(&(gamepad, button))
+                })
+            }
+
+            fn is_gamepad_button_up(&self, gamepad: i32, button: GamepadButton) -> bool {
+                !self.is_gamepad_button_down(gamepad, button)
+            }
+
+            fn get_gamepad_axis_movement(&self, gamepad: i32, axis: GamepadAxis) -> f32 {
+                Headless::with(|state| {
+                    state
+                        .gamepad_axes
+                        .get(&(gamepad, axis))
+                        .copied()
+                        .unwrap_or_default()
+                })
+            }
+
+            fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+                Headless::with(|state| {
+                    state.buttons.contains(&button) && !state.prev_buttons.contains(&button)
+                })
+            }
+
+            fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+                Headless::with(|state| state.buttons.contains(&button))
+            }
+
+            fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+                Headless::with(|state| {
+                    !state.buttons.contains(&button) && state.prev_buttons.contains(&button)
+                })
+            }
+
+            fn is_mouse_button_up(&self, button: MouseButton) -> bool {
+                !self.is_mouse_button_down(button)
+            }
+
+            fn get_mouse_x(&self) -> i32 {
+                self.get_mouse_position().x as i32
+            }
+
+            fn get_mouse_y(&self) -> i32 {
+                self.get_mouse_position().y as i32
+            }
+
+            fn get_mouse_position(&self) -> Vector2 {
+                Headless::with(|state| state.mouse_position)
+            }
+
+            fn get_mouse_delta(&self) -> Vector2 {
+                Headless::with(|state| Vector2 {
+                    x: state.mouse_position.x - state.prev_mouse_position.x,
+                    y: state.mouse_position.y - state.prev_mouse_position.y,
+                })
+            }
+
+            fn set_mouse_position(&self, x: i32, y: i32) {
+                Headless::set_mouse_position(Vector2 {
+                    x: x as f32,
+                    y: y as f32,
+                });
+            }
+
+            fn get_mouse_wheel_move(&self) -> f32 {
+                Headless::with(|state| {
+                    if state.mouse_wheel.x.abs() > state.mouse_wheel.y.abs() {
+                        state.mouse_wheel.x
+                    } else {
+                        state.mouse_wheel.y
+                    }
+                })
+            }
+
+            fn get_mouse_wheel_move_v(&self) -> Vector2 {
+                Headless::with(|state| state.mouse_wheel)
+            }
+        }
+    };
+}
+
+macro_rules! headless_rtext {
+    () => {
+        record! {
+            Rtext;
+            fn draw_fps(&self, x: i32, y: i32);
+            fn draw_text_codepoint(&self, font: Font, codepoint: i32, position: Vector2, font_size: f32, tint: Color);
+            fn draw_text_codepoints(&self, font: Font, codepoints: Codepoints, position: Vector2, font_size: f32, spacing: f32, tint: Color);
+        }
+
+        headless! {
+            Rtext;
+            fn draw_text(&self, text: impl Display, x: i32, y: i32, font_size: i32, color: Color) {
+                Headless::record(
+                    "draw_text",
+                    format!("{:?}", (text.to_string(), x, y, font_size, color)),
+                );
+            }
+
+            fn draw_text_ex(
+                &self,
+                font: Font,
+                text: impl Display,
+                position: Vector2,
+                font_size: f32,
+                spacing: f32,
+                tint: Color,
+            ) {
+                Headless::record(
+                    "draw_text_ex",
+                    format!(
+                        "{:?}",
+                        (font, text.to_string(), position, font_size, spacing, tint)
+                    ),
+                );
+            }
+
+            fn draw_text_pro(
+                &self,
+                font: Font,
+                text: impl Display,
+                position: Vector2,
+                origin: Vector2,
+                rotation: f32,
+                font_size: f32,
+                spacing: f32,
+                tint: Color,
+            ) {
+                Headless::record(
+                    "draw_text_pro",
+                    format!(
+                        "{:?}",
+                        (
+                            font,
+                            text.to_string(),
+                            position,
+                            origin,
+                            rotation,
+                            font_size,
+                            spacing,
+                            tint
+                        )
+                    ),
+                );
+            }
+
+            /// Every glyph is half font size wide
+            fn measure_text(&self, text: impl Display, font_size: i32) -> i32 {
+                text.to_string().chars().count() as i32 * font_size / 2
+            }
+
+            /// Every glyph is half font size wide
+            fn measure_text_ex(
+                &self,
+                font: Font,
+                text: impl Display,
+                font_size: f32,
+                spacing: f32,
+            ) -> Vector2 {
+                let count = text.to_string().chars().count() as f32;
+                Vector2 {
+                    x: count * font_size / 2.0 + (count - 1.0).max(0.0) * spacing,
+                    y: font_size,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! headless_rtextures {
+    () => {
+        record! {
+            Rtextures;
+            fn draw_texture(&self, texture: Texture2D, x: i32, y: i32, tint: Color);
+            fn draw_texture_v(&self, texture: Texture2D, position: Vector2, tint: Color);
+            fn draw_texture_ex(&self, texture: Texture2D, position: Vector2, rotation: f32, scale: f32, tint: Color);
+            fn draw_texture_rec(&self, texture: Texture2D, source: Rectangle, position: Vector2, tint: Color);
+            fn draw_texture_pro(&self, texture: Texture2D, source: Rectangle, dest: Rectangle, origin: Vector2, rotation: f32, tint: Color);
+            fn draw_texture_n_patch(&self, texture: Texture2D, info: NPatchInfo, dest: Rectangle, origin: Vector2, rotation: f32, tint: Color);
+            fn unload_render_texture(&self, target: RenderTexture);
+        }
+
+        headless! {
+            Rtextures;
+            /// No framebuffer is allocated, only the size is kept
+            fn load_render_texture(&self, width: i32, height: i32) -> RenderTexture {
+                Headless::record("load_render_texture", format!("{:?}", (&width, &height)));
+                let texture = Texture2D {
+                    id: 0,
+                    width,
+                    height,
+                    mipmaps: 1,
+                    format: PixelFormat::R8g8b8a8 as i32,
+                };
+                RenderTexture {
+                    id: 0,
+                    texture,
+                    depth: texture,
+                }
+            }
+
+            fn is_render_texture_ready(&self, target: RenderTexture) -> bool {
+                true
+            }
+        }
+    };
+}
+
+macro_rules! headless_raudio {
+    () => {
+        headless! {
+            Raudio;
+            fn init_audio_device(&self) {
+                Headless::with(|state| state.audio_ready = true);
+            }
+
+            fn close_audio_device(&self) {
+                Headless::with(|state| state.audio_ready = false);
+            }
+
+            fn is_audio_device_ready(&self) -> bool {
+                Headless::with(|state| state.audio_ready)
+            }
+
+            fn set_master_volume(&self, volume: f32) {
+                Headless::with(|state| state.master_volume = volume);
+            }
+
+            fn get_master_volume(&self) -> f32 {
+                Headless::with(|state| state.master_volume)
+            }
+        }
+    };
+}
+
+impl Rcore for PlainConnector {
+    headless_rcore!();
+}
+impl Rtextures for PlainConnector {
+    headless_rtextures!();
+}
+impl Rtext for PlainConnector {
+    headless_rtext!();
+}
+impl Raudio for PlainConnector {
+    headless_raudio!();
+}
+
+impl Rcore for Connector2D {
+    headless_rcore!();
+}
+impl Rshapes for Connector2D {
+    record! {
+        Rshapes;
+        fn set_shapes_texture(&self, texture: Texture2D, source: Rectangle);
+        fn draw_pixel(&self, x: i32, y: i32, color: Color);
+        fn draw_pixel_v(&self, position: Vector2, color: Color);
+        fn draw_line(&self, start_x: i32, start_y: i32, end_x: i32, end_y: i32, color: Color);
+        fn draw_line_v(&self, start: Vector2, end: Vector2, color: Color);
+        fn draw_line_ex(&self, start: Vector2, end: Vector2, thick: f32, color: Color);
+        fn draw_line_strip(&self, points: &mut Vec<Vector2>, color: Color);
+        fn draw_line_bezier(&self, start: Vector2, end: Vector2, thick: f32, color: Color);
+        fn draw_circle(&self, center_x: i32, center_y: i32, radius: f32, color: Color);
+        fn draw_circle_sector(&self, center: Vector2, radius: f32, start_angle: f32, end_angle: f32, segments: i32, color: Color);
+        fn draw_circle_sector_lines(&self, center: Vector2, radius: f32, start_angle: f32, end_angle: f32, segments: i32, color: Color);
+        fn draw_circle_gradient(&self, center_x: i32, center_y: i32, radius: f32, color1: Color, color2: Color);
+        fn draw_circle_v(&self, center: Vector2, radius: f32, color: Color);
+        fn draw_circle_lines(&self, center_x: i32, center_y: i32, radius: f32, color: Color);
+        fn draw_circle_lines_v(&self, center: Vector2, radius: f32, color: Color);
+        fn draw_ellipse(&self, center_x: i32, center_y: i32, radius_h: f32, radius_v: f32, color: Color);
+        fn draw_ellipse_lines(&self, center_x: i32, center_y: i32, radius_h: f32, radius_v: f32, color: Color);
+        fn draw_ring(&self, center: Vector2, inner_radius: f32, outer_radius: f32, start_angle: f32, end_angle: f32, segments: i32, color: Color);
+        fn draw_ring_lines(&self, center: Vector2, inner_radius: f32, outer_radius: f32, start_angle: f32, end_angle: f32, segments: i32, color: Color);
+        fn draw_rectangle(&self, x: i32, y: i32, width: i32, height: i32, color: Color);
+        fn draw_rectangle_v(&self, position: Vector2, size: Vector2, color: Color);
+        fn draw_rectangle_rec(&self, rec: Rectangle, color: Color);
+        fn draw_rectangle_pro(&self, rec: Rectangle, origin: Vector2, rotation: f32, color: Color);
+        fn draw_rectangle_gradient_v(&self, x: i32, y: i32, width: i32, height: i32, color1: Color, color2: Color);
+        fn draw_rectangle_gradient_h(&self, x: i32, y: i32, width: i32, height: i32, color1: Color, color2: Color);
+        fn draw_rectangle_gradient_ex(&self, rec: Rectangle, col1: Color, col2: Color, col3: Color, col4: Color);
+        fn draw_rectangle_lines(&self, x: i32, y: i32, width: i32, height: i32, color: Color);
+        fn draw_rectangle_lines_ex(&self, rec: Rectangle, thick: f32, color: Color);
+        fn draw_rectangle_rounded(&self, rec: Rectangle, roundness: f32, segments: i32, color: Color);
+        fn draw_rectangle_rounded_lines(&self, rec: Rectangle, roundness: f32, segments: i32, thick: f32, color: Color);
+        fn draw_triangle(&self, v1: Vector2, v2: Vector2, v3: Vector2, color: Color);
+        fn draw_triangle_lines(&self, v1: Vector2, v2: Vector2, v3: Vector2, color: Color);
+        fn draw_triangle_fan(&self, points: &mut Vec<Vector2>, color: Color);
+        fn draw_triangle_strip(&self, points: &mut Vec<Vector2>, color: Color);
+        fn draw_poly(&self, center: Vector2, sides: i32, radius: f32, rotation: f32, color: Color);
+        fn draw_poly_lines(&self, center: Vector2, sides: i32, radius: f32, rotation: f32, color: Color);
+        fn draw_poly_lines_ex(&self, center: Vector2, sides: i32, radius: f32, rotation: f32, thick: f32, color: Color);
+        fn draw_spline_linear(&self, points: &mut Vec<Vector2>, thick: f32, color: Color);
+        fn draw_spline_basis(&self, points: &mut Vec<Vector2>, thick: f32, color: Color);
+        fn draw_spline_catmull_rom(&self, points: &mut Vec<Vector2>, thick: f32, color: Color);
+        fn draw_spline_berzier_quadratic(&self, points: &mut Vec<Vector2>, thick: f32, color: Color);
+        fn draw_spline_berzier_cubic(&self, points: &mut Vec<Vector2>, thick: f32, color: Color);
+        fn draw_spline_segment_linear(&self, p1: Vector2, p2: Vector2, thick: f32, color: Color);
+        fn draw_spline_segment_basis(&self, p1: Vector2, p2: Vector2, p3: Vector2, p4: Vector2, thick: f32, color: Color);
+        fn draw_spline_segment_catmull_rom(&self, p1: Vector2, p2: Vector2, p3: Vector2, p4: Vector2, thick: f32, color: Color);
+        fn draw_spline_segment_bezier_quadratic(&self, p1: Vector2, p2: Vector2, p3: Vector2, thick: f32, color: Color);
+        fn draw_spline_segment_bezier_cubic(&self, p1: Vector2, p2: Vector2, p3: Vector2, p4: Vector2, thick: f32, color: Color);
+    }
+}
+impl Rtextures for Connector2D {
+    headless_rtextures!();
+}
+impl Rtext for Connector2D {
+    headless_rtext!();
+}
+impl Raudio for Connector2D {
+    headless_raudio!();
+}
+
+impl Rcore for Connector3D {
+    headless_rcore!();
+}
+impl Rtextures for Connector3D {
+    headless_rtextures!();
+}
+impl Rmodels for Connector3D {
+    record! {
+        Rmodels;
+        fn draw_line_3d(&self, start: Vector3, end: Vector3, color: Color);
+        fn draw_point_3d(&self, position: Vector3, color: Color);
+        fn draw_circle_3d(&self, center: Vector3, radius: f32, rotation_axis: Vector3, rotation_angle: f32, color: Color);
+        fn draw_triangle_3d(&self, v1: Vector3, v2: Vector3, v3: Vector3, color: Color);
+        fn draw_triangle_strip_3d(&self, points: &mut Vec<Vector3>, color: Color);
+        fn draw_cube(&self, position: Vector3, width: f32, height: f32, length: f32, color: Color);
+        fn draw_cube_v(&self, position: Vector3, size: Vector3, color: Color);
+        fn draw_cube_wires(&self, position: Vector3, width: f32, height: f32, length: f32, color: Color);
+        fn draw_cube_wires_v(&self, position: Vector3, size: Vector3, color: Color);
+        fn draw_sphere(&self, center: Vector3, radius: f32, color: Color);
+        fn draw_sphere_ex(&self, center: Vector3, radius: f32, rings: i32, slices: i32, color: Color);
+        fn draw_sphere_wires(&self, center: Vector3, radius: f32, rings: i32, slices: i32, color: Color);
+        fn draw_cylinder(&self, position: Vector3, radius_top: f32, radius_bottom: f32, height: f32, slices: i32, color: Color);
+        fn draw_cylinder_ex(&self, start_pos: Vector3, end_pos: Vector3, start_radius: f32, end_radius: f32, sides: i32, color: Color);
+        fn draw_cylinder_wires(&self, position: Vector3, radius_top: f32, radius_bottom: f32, height: f32, slices: i32, color: Color);
+        fn draw_cylinder_wires_ex(&self, start_pos: Vector3, end_pos: Vector3, start_radius: f32, end_radius: f32, sides: i32, color: Color);
+        fn draw_capsule(&self, start_pos: Vector3, end_pos: Vector3, radius: f32, slices: i32, rings: i32, color: Color);
+        fn draw_capsule_wires(&self, start_pos: Vector3, end_pos: Vector3, radius: f32, slices: i32, rings: i32, color: Color);
+        fn draw_plane(&self, center: Vector3, size: Vector2, color: Color);
+        fn draw_ray(&self, ray: Ray, color: Color);
+        fn draw_grid(&self, slices: i32, spacing: f32);
+        fn draw_model(&self, model: Model, position: Vector3, scale: f32, tint: Color);
+        fn draw_model_ex(&self, model: Model, position: Vector3, rotation_axis: Vector3, rotation_angle: f32, scale: Vector3, tint: Color);
+        fn draw_model_wires(&self, model: Model, position: Vector3, scale: f32, tint: Color);
+        fn draw_model_wires_ex(&self, model: Model, position: Vector3, rotation_axis: Vector3, rotation_angle: f32, scale: Vector3, tint: Color);
+        fn draw_bounding_box(&self, box_: BoundingBox, color: Color);
+        fn draw_billboard(&self, camera: Camera3D, texture: Texture2D, position: Vector3, size: f32, tint: Color);
+        fn draw_billboard_rec(&self, camera: Camera3D, texture: Texture2D, source: Rectangle, position: Vector3, size: Vector2, tint: Color);
+        fn draw_billboard_pro(&self, camera: Camera3D, texture: Texture2D, source: Rectangle, position: Vector3, up: Vector3, size: Vector2, origin: Vector2, rotation: f32, tint: Color);
+        fn draw_mesh(&self, mesh: Mesh, material: Material, transform: Matrix);
+        fn draw_mesh_instanced(&self, mesh: Mesh, material: Material, transforms: &[Matrix]);
+    }
+}
+impl Raudio for Connector3D {
+    headless_raudio!();
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_switch_connectors_at_runtime() {
+        Headless::reset();
+        assert!(Headless::is_enabled());
+        assert_eq!(PlainConnector.get_render_width(), 800);
+        assert!(Headless::is_enabled());
+        Headless::disable();
+        assert!(!Headless::is_enabled());
+    }
+
+    #[test]
+    fn it_should_fake_resources() {
+        Headless::reset();
+        let target = OwnedRenderTexture::load(64, 32);
+        assert_eq!(target.as_ref().texture.width, 64);
+        assert_eq!(target.as_ref().texture.height, 32);
+        let image = OwnedImage::load("missing.png").unwrap();
+        let texture = OwnedTexture::load_from_image(image.as_ref()).unwrap();
+        assert_eq!(texture.as_ref().id, 0);
+        let sound = PlainConnector.load_sound("missing.ogg").unwrap();
+        PlainConnector.unload_sound(sound);
+    }
+
+    #[test]
+    fn it_should_only_repeat_keys_when_scripted() {
+        Headless::reset();
+        Headless::press_key(KeyboardKey::A);
+        Headless::next_frame();
+        assert!(PlainConnector.is_key_down(KeyboardKey::A));
+        assert!(!PlainConnector.is_key_pressed_repeat(KeyboardKey::A));
+        Headless::repeat_key(KeyboardKey::A);
+        assert!(PlainConnector.is_key_pressed_repeat(KeyboardKey::A));
+        Headless::next_frame();
+        assert!(!PlainConnector.is_key_pressed_repeat(KeyboardKey::A));
+    }
+}
@@ -1,9 +1,13 @@
 #[cfg(feature = "fake-fs")]
 mod fake_fullscreen;
+#[cfg(feature = "headless")]
+mod headless;
 #[cfg(feature = "storage")]
 mod store;
 
 #[cfg(feature = "fake-fs")]
 pub use fake_fullscreen::{start_fullscreen, FakeFullscreen};
+#[cfg(feature = "headless")]
+pub use headless::{DrawCall, Headless};
 #[cfg(feature = "storage")]
 pub use store::XDGStore;
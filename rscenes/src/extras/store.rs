@@ -1,3 +1,4 @@
+use crate::error::Error;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     env,
@@ -11,29 +12,27 @@ use std::{
 pub struct XDGStore;
 
 impl XDGStore {
-    pub fn init_storage(app_name: &str) -> Result<(), String> {
+    pub fn init_storage(app_name: &str) -> Result<(), Error> {
         let data_home = app_data_home(app_name);
         if !data_home.exists() {
-            fs::create_dir_all(data_home).map_err(|e| format!("{:?}", e))
+            Ok(fs::create_dir_all(data_home)?)
         } else {
             Ok(())
         }
     }
 
-    pub fn save<T: Serialize>(app_name: &str, bundle: &str, data: T) -> Result<(), String> {
-        let payload = serde_json::to_string(&data).map_err(|e| format!("{:?}", e))?;
+    pub fn save<T: Serialize>(app_name: &str, bundle: &str, data: T) -> Result<(), Error> {
+        let payload = serde_json::to_string(&data)?;
         let mut file = bundle_file(app_name, bundle, RW::RW)?;
-        file.write_all(payload.as_bytes())
-            .map_err(|e| format!("{:?}", e))?;
+        file.write_all(payload.as_bytes())?;
         Ok(())
     }
 
-    pub fn retrieve<T: DeserializeOwned>(app_name: &str, bundle: &str) -> Result<T, String> {
+    pub fn retrieve<T: DeserializeOwned>(app_name: &str, bundle: &str) -> Result<T, Error> {
         let mut file = bundle_file(app_name, bundle, RW::RO)?;
         let mut content = String::new();
-        file.read_to_string(&mut content)
-            .map_err(|e| format!("{:?}", e))?;
-        let res: T = serde_json::from_str(&content).map_err(|e| format!("{:?}", e))?;
+        file.read_to_string(&mut content)?;
+        let res: T = serde_json::from_str(&content)?;
         Ok(res)
     }
 }
@@ -42,14 +41,14 @@ fn app_data_home(app_name: &str) -> PathBuf {
     xdg_data_home().join(app_name)
 }
 
-fn bundle_file(app_name: &str, bundle: &str, mode: RW) -> Result<File, String> {
+fn bundle_file(app_name: &str, bundle: &str, mode: RW) -> Result<File, Error> {
     let mut storage = app_data_home(app_name).join(bundle);
     storage.set_extension("json");
-    match mode {
+    let file = match mode {
         RW::RO => File::open(storage),
         RW::RW => File::create(storage),
-    }
-    .map_err(|e| format!("{:?}", e))
+    }?;
+    Ok(file)
 }
 
 enum RW {
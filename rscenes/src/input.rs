@@ -0,0 +1,427 @@
+use rscenes_raylib_connector::{assets::*, interface::*};
+use std::collections::HashMap;
+
+#[cfg(feature = "storage")]
+use crate::{error::Error, extras::XDGStore};
+#[cfg(feature = "storage")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "storage")]
+const BUNDLE: &str = "input";
+
+/// Physical input an action can be bound to
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "storage", derive(Serialize, Deserialize))]
+pub enum Input {
+    Key(#[cfg_attr(feature = "storage", serde(with = "code"))] KeyboardKey),
+    Mouse(#[cfg_attr(feature = "storage", serde(with = "code"))] MouseButton),
+    Gamepad(
+        i32,
+        #[cfg_attr(feature = "storage", serde(with = "code"))] GamepadButton,
+    ),
+}
+
+impl Input {
+    /// Check whether the input has been pressed once
+    pub fn is_pressed(self, rl: &impl Rcore) -> bool {
+        match self {
+            Self::Key(key) => rl.is_key_pressed(key),
+            Self::Mouse(button) => rl.is_mouse_button_pressed(button),
+            Self::Gamepad(gamepad, button) => rl.is_gamepad_button_pressed(gamepad, button),
+        }
+    }
+
+    /// Check whether the input is being pressed
+    pub fn is_down(self, rl: &impl Rcore) -> bool {
+        match self {
+            Self::Key(key) => rl.is_key_down(key),
+            Self::Mouse(button) => rl.is_mouse_button_down(button),
+            Self::Gamepad(gamepad, button) => rl.is_gamepad_button_down(gamepad, button),
+        }
+    }
+
+    /// Check whether the input has been released once
+    pub fn is_released(self, rl: &impl Rcore) -> bool {
+        match self {
+            Self::Key(key) => rl.is_key_released(key),
+            Self::Mouse(button) => rl.is_mouse_button_released(button),
+            Self::Gamepad(gamepad, button) => rl.is_gamepad_button_released(gamepad, button),
+        }
+    }
+
+    /// Check whether the input is a modifier key, like Ctrl or Shift
+    pub fn is_modifier(self) -> bool {
+        matches!(
+            self,
+            Self::Key(
+                KeyboardKey::LeftControl
+                    | KeyboardKey::RightControl
+                    | KeyboardKey::LeftShift
+                    | KeyboardKey::RightShift
+                    | KeyboardKey::LeftAlt
+                    | KeyboardKey::RightAlt
+                    | KeyboardKey::LeftSuper
+                    | KeyboardKey::RightSuper
+            )
+        )
+    }
+}
+
+impl From<KeyboardKey> for Input {
+    fn from(key: KeyboardKey) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<MouseButton> for Input {
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+/// Inputs held together, like Ctrl+LMB
+///
+/// A chord is pressed when its last input goes down while the others are held, and released
+/// when one of its non-modifier inputs goes up while the others are still held. Letting go of a
+/// modifier first never releases it, Ctrl+LMB only fires on the button release while Ctrl is
+/// down. Chords made of modifiers only are released by any of them.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "storage",
+    derive(Serialize, Deserialize),
+    serde(transparent)
+)]
+pub struct Chord(Vec<Input>);
+
+impl Chord {
+    pub fn new(inputs: impl IntoIterator<Item = Input>) -> Self {
+        Self(inputs.into_iter().collect())
+    }
+
+    pub fn inputs(&self) -> &[Input] {
+        &self.0
+    }
+
+    pub fn is_pressed(&self, rl: &impl Rcore) -> bool {
+        self.is_down(rl) && self.0.iter().any(|input| input.is_pressed(rl))
+    }
+
+    pub fn is_down(&self, rl: &impl Rcore) -> bool {
+        !self.0.is_empty() && self.0.iter().all(|input| input.is_down(rl))
+    }
+
+    pub fn is_released(&self, rl: &impl Rcore) -> bool {
+        let modifiers_only = self.0.iter().all(|input| input.is_modifier());
+        let is_trigger = |input: &Input| modifiers_only || !input.is_modifier();
+        let released = |input: &Input| is_trigger(input) && input.is_released(rl);
+        self.0.iter().any(released)
+            && self
+                .0
+                .iter()
+                .all(|input| input.is_down(rl) || released(input))
+    }
+}
+
+impl<T: Into<Input>> From<T> for Chord {
+    fn from(input: T) -> Self {
+        Self(vec![input.into()])
+    }
+}
+
+impl<const N: usize> From<[Input; N]> for Chord {
+    fn from(inputs: [Input; N]) -> Self {
+        Self(inputs.to_vec())
+    }
+}
+
+/// Source for an analog action, ranging from -1.0 to 1.0
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "storage", derive(Serialize, Deserialize))]
+pub enum Axis {
+    Gamepad(
+        i32,
+        #[cfg_attr(feature = "storage", serde(with = "code"))] GamepadAxis,
+    ),
+    /// Digital inputs pushing towards each end
+    Buttons { negative: Chord, positive: Chord },
+}
+
+impl Axis {
+    pub fn buttons(negative: impl Into<Chord>, positive: impl Into<Chord>) -> Self {
+        Self::Buttons {
+            negative: negative.into(),
+            positive: positive.into(),
+        }
+    }
+
+    pub fn value(&self, rl: &impl Rcore) -> f32 {
+        match self {
+            Self::Gamepad(gamepad, axis) => rl.get_gamepad_axis_movement(*gamepad, *axis),
+            Self::Buttons { negative, positive } => {
+                let mut value = 0.0;
+                if negative.is_down(rl) {
+                    value -= 1.0;
+                }
+                if positive.is_down(rl) {
+                    value += 1.0;
+                }
+                value
+            }
+        }
+    }
+}
+
+/// Named actions bound to physical inputs
+///
+/// Each action takes any number of bindings and triggers when any of them does. Button
+/// actions are queried with `is_pressed()`, `is_down()` and `is_released()`, analog ones with
+/// `axis()`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "storage", derive(Serialize, Deserialize))]
+pub struct InputMap {
+    /// Axis values closer to zero than this are ignored
+    pub dead_zone: f32,
+    buttons: HashMap<String, Vec<Chord>>,
+    axes: HashMap<String, Vec<Axis>>,
+}
+
+impl InputMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind a button action to an input or chord, keeping the previous bindings
+    pub fn bind(&mut self, action: impl Into<String>, chord: impl Into<Chord>) -> &mut Self {
+        self.buttons
+            .entry(action.into())
+            .or_default()
+            .push(chord.into());
+        self
+    }
+
+    /// Bind an analog action, keeping the previous bindings
+    pub fn bind_axis(&mut self, action: impl Into<String>, axis: Axis) -> &mut Self {
+        self.axes.entry(action.into()).or_default().push(axis);
+        self
+    }
+
+    /// Drop every binding of an action
+    pub fn unbind(&mut self, action: &str) -> &mut Self {
+        self.buttons.remove(action);
+        self.axes.remove(action);
+        self
+    }
+
+    pub fn bindings(&self, action: &str) -> &[Chord] {
+        self.buttons.get(action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn axis_bindings(&self, action: &str) -> &[Axis] {
+        self.axes.get(action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Check whether an action has been triggered once
+    pub fn is_pressed(&self, rl: impl Rcore, action: &str) -> bool {
+        self.bindings(action)
+            .iter()
+            .any(|chord| chord.is_pressed(&rl))
+    }
+
+    /// Check whether an action is being held
+    pub fn is_down(&self, rl: impl Rcore, action: &str) -> bool {
+        self.bindings(action).iter().any(|chord| chord.is_down(&rl))
+    }
+
+    /// Check whether an action has been let go once
+    pub fn is_released(&self, rl: impl Rcore, action: &str) -> bool {
+        let chords = self.bindings(action);
+        chords.iter().any(|chord| chord.is_released(&rl))
+            && !chords.iter().any(|chord| chord.is_down(&rl))
+    }
+
+    /// Value of an analog action, the binding pushed furthest wins
+    pub fn axis(&self, rl: impl Rcore, action: &str) -> f32 {
+        let value = self
+            .axis_bindings(action)
+            .iter()
+            .map(|axis| axis.value(&rl))
+            .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+            .unwrap_or_default();
+        if value.abs() < self.dead_zone {
+            0.0
+        } else {
+            value.clamp(-1.0, 1.0)
+        }
+    }
+
+    /// Retrieve bindings saved by `save()`
+    #[cfg(feature = "storage")]
+    pub fn load(app_name: &str) -> Result<Self, Error> {
+        XDGStore::retrieve(app_name, BUNDLE)
+    }
+
+    /// Save bindings into the application data directory
+    #[cfg(feature = "storage")]
+    pub fn save(&self, app_name: &str) -> Result<(), Error> {
+        XDGStore::save(app_name, BUNDLE, self)
+    }
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self {
+            dead_zone: 0.2,
+            buttons: HashMap::new(),
+            axes: HashMap::new(),
+        }
+    }
+}
+
+/// Raylib enums are stored by their numeric code
+#[cfg(any(feature = "config", feature = "storage"))]
+pub(crate) mod code {
+    use num_traits::{FromPrimitive, ToPrimitive};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T: ToPrimitive, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(value.to_i64().unwrap_or_default())
+    }
+
+    pub fn deserialize<'de, T: FromPrimitive, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let code = i64::deserialize(deserializer)?;
+        T::from_i64(code).ok_or_else(|| de::Error::custom(format!("unknown input code {code}")))
+    }
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(all(test, feature = "headless"))]
+mod tests {
+    use super::*;
+    use crate::{connectors::PlainConnector, extras::Headless};
+
+    fn controls() -> InputMap {
+        let mut map = InputMap::new();
+        map.bind("pause", KeyboardKey::P)
+            .bind("pause", KeyboardKey::Pause)
+            .bind(
+                "mark",
+                [
+                    Input::Key(KeyboardKey::LeftControl),
+                    Input::Mouse(MouseButton::Left),
+                ],
+            )
+            .bind("jump", Input::Gamepad(0, GamepadButton::RightFaceDown))
+            .bind_axis("walk", Axis::buttons(KeyboardKey::Left, KeyboardKey::Right))
+            .bind_axis("walk", Axis::Gamepad(0, GamepadAxis::LeftX));
+        map
+    }
+
+    #[test]
+    fn it_should_trigger_any_binding() {
+        Headless::reset();
+        let map = controls();
+        Headless::press_key(KeyboardKey::Pause);
+        assert!(map.is_pressed(PlainConnector, "pause"));
+        assert!(map.is_down(PlainConnector, "pause"));
+        assert!(!map.is_released(PlainConnector, "pause"));
+        Headless::next_frame();
+        assert!(!map.is_pressed(PlainConnector, "pause"));
+        Headless::release_key(KeyboardKey::Pause);
+        assert!(map.is_released(PlainConnector, "pause"));
+        assert!(!map.is_down(PlainConnector, "unknown"));
+    }
+
+    #[test]
+    fn it_should_require_the_whole_chord() {
+        Headless::reset();
+        let map = controls();
+        Headless::press_mouse_button(MouseButton::Left);
+        assert!(!map.is_pressed(PlainConnector, "mark"));
+        Headless::next_frame();
+        Headless::press_key(KeyboardKey::LeftControl);
+        assert!(map.is_pressed(PlainConnector, "mark"));
+        Headless::next_frame();
+        assert!(map.is_down(PlainConnector, "mark"));
+        assert!(!map.is_pressed(PlainConnector, "mark"));
+        Headless::click(MouseButton::Left);
+        assert!(map.is_released(PlainConnector, "mark"));
+    }
+
+    #[test]
+    fn it_should_not_release_chords_by_their_modifiers() {
+        Headless::reset();
+        let map = controls();
+        Headless::press_key(KeyboardKey::LeftControl);
+        Headless::press_mouse_button(MouseButton::Left);
+        Headless::next_frame();
+        assert!(map.is_down(PlainConnector, "mark"));
+        Headless::tap_key(KeyboardKey::LeftControl);
+        assert!(!map.is_released(PlainConnector, "mark"));
+        Headless::next_frame();
+        Headless::click(MouseButton::Left);
+        assert!(!map.is_released(PlainConnector, "mark"));
+
+        let mut map = InputMap::new();
+        map.bind("shift", KeyboardKey::LeftShift);
+        Headless::tap_key(KeyboardKey::LeftShift);
+        assert!(map.is_released(PlainConnector, "shift"));
+    }
+
+    #[test]
+    fn it_should_read_gamepad_buttons() {
+        Headless::reset();
+        let map = controls();
+        Headless::press_gamepad_button(0, GamepadButton::RightFaceDown);
+        assert!(map.is_pressed(PlainConnector, "jump"));
+        Headless::next_frame();
+        Headless::release_gamepad_button(0, GamepadButton::RightFaceDown);
+        assert!(map.is_released(PlainConnector, "jump"));
+    }
+
+    #[test]
+    fn it_should_combine_axes() {
+        Headless::reset();
+        let map = controls();
+        assert_eq!(map.axis(PlainConnector, "walk"), 0.0);
+        Headless::press_key(KeyboardKey::Left);
+        assert_eq!(map.axis(PlainConnector, "walk"), -1.0);
+        Headless::release_key(KeyboardKey::Left);
+        Headless::set_gamepad_axis(0, GamepadAxis::LeftX, 0.1);
+        assert_eq!(map.axis(PlainConnector, "walk"), 0.0);
+        Headless::set_gamepad_axis(0, GamepadAxis::LeftX, 0.5);
+        assert_eq!(map.axis(PlainConnector, "walk"), 0.5);
+    }
+
+    #[test]
+    fn it_should_unbind_actions() {
+        let mut map = controls();
+        assert_eq!(map.bindings("pause").len(), 2);
+        map.unbind("pause").unbind("walk");
+        assert!(map.bindings("pause").is_empty());
+        assert!(map.axis_bindings("walk").is_empty());
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn it_should_serialize_bindings() {
+        let map = controls();
+        let json = serde_json::to_string(&map).unwrap();
+        let restored: InputMap = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, map);
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn it_should_reject_unknown_codes() {
+        let res = serde_json::from_str::<Input>(r#"{"Key":-1}"#);
+        assert!(res.is_err());
+    }
+}
@@ -1,18 +1,53 @@
 #![feature(trait_alias)]
+mod cache;
+mod cli;
+mod config;
 mod connectors;
+mod crash;
+mod error;
+mod input;
+mod logger;
 mod macros;
 mod manager;
+mod overlay;
+mod profiler;
+mod replay;
 mod scene;
+mod scheduler;
 mod state;
+mod timestep;
+mod transition;
+mod tween;
 
 pub mod extras;
 
+pub use error::Error;
+#[doc(hidden)]
+pub use scene::SceneFields;
+pub use rscenes_raylib_connector::RaylibError;
+
 pub mod prelude {
     pub use super::setup;
+    pub use crate::cache::{Asset, AssetCache};
+    pub use crate::cli::Options;
+    pub use crate::config::Config;
     pub use crate::connectors::*;
+    pub use crate::crash::ErrorPolicy;
+    pub use crate::error::Error;
+    pub use crate::input::{Axis, Chord, Input, InputMap};
+    pub use crate::logger::Logger;
     pub use crate::manager::*;
-    pub use crate::scene::Scene;
-    pub use crate::state::State;
-    pub use rscenes_macros::draw;
-    pub use rscenes_raylib_connector::{assets::*, interface::*};
+    pub use crate::overlay::DebugOverlay;
+    pub use crate::profiler::{ProfileScope, Profiler};
+    pub use crate::replay::Replay;
+    pub use crate::scene::{Payload, Scene, SceneHandle};
+    pub use crate::scheduler::{Scheduler, Script, TaskId};
+    pub use crate::state::{SceneMatcher, ScenePredicate, State};
+    pub use crate::timestep::FixedTimestep;
+    pub use crate::transition::{Direction, Transition};
+    pub use crate::tween::{
+        Animation, Delay, Ease, Lerp, Parallel, Repeat, Sequence, Tween, TweenId, Tweened, Tweens,
+    };
+    pub use rscenes_macros::{draw, scene, Scene};
+    pub use rscenes_raylib_connector::{assets::*, interface::*, RaylibError};
 }
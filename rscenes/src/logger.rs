@@ -0,0 +1,242 @@
+use crate::error::Error;
+use log::{LevelFilter, Log, Metadata, Record};
+use std::{
+    env,
+    ffi::OsString,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Size a log file grows up to before being rotated
+const MAX_FILE_SIZE: u64 = 1 << 20;
+/// Rotated files kept, suffixed from `.1`, the newest, to `.3`
+const KEEP_FILES: usize = 3;
+
+/// `log` crate logger writing to stderr, and optionally to a rotating file
+///
+/// [`Rscenes`](crate::prelude::Rscenes) installs one on start, along with the raylib trace log
+/// forwarding, unless the game already set its own logger.
+#[derive(Debug)]
+pub struct Logger {
+    level: LevelFilter,
+    file: Option<Mutex<LogFile>>,
+}
+
+impl Logger {
+    pub fn new(level: LevelFilter) -> Self {
+        Self { level, file: None }
+    }
+
+    /// Also write into `path`, relative paths are taken from the XDG state directory
+    /// (`~/.local/state`), like `mygame/mygame.log`
+    ///
+    /// The file is rotated once it reaches 1 MiB, keeping the last three.
+    pub fn with_file(mut self, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = xdg_state_home().join(path);
+        self.file = Some(Mutex::new(LogFile::open(path, MAX_FILE_SIZE)?));
+        Ok(self)
+    }
+
+    /// Install as the `log` crate logger, it fails when one is set already
+    pub fn init(self) -> Result<(), Error> {
+        let level = self.level;
+        log::set_boxed_logger(Box::new(self))
+            .map_err(|err| Error::InvalidState(err.to_string()))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "{:<5} {}: {}",
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        eprintln!("{}", line);
+        if let Some(file) = &self.file {
+            if let Ok(mut file) = file.lock() {
+                // There's nowhere left to report it
+                let _ = file.write_line(&format!("{} {}", timestamp(SystemTime::now()), line));
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file {
+            if let Ok(mut file) = file.lock() {
+                let _ = file.file.flush();
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_size: u64,
+}
+
+impl LogFile {
+    fn open(path: PathBuf, max_size: u64) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file,
+            size,
+            max_size,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let len = line.len() as u64 + 1;
+        if self.size > 0 && self.size + len > self.max_size {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{}", line)?;
+        self.size += len;
+        Ok(())
+    }
+
+    /// Shift the older files, dropping the oldest one, and start over
+    fn rotate(&mut self) -> io::Result<()> {
+        for index in (1..KEEP_FILES).rev() {
+            let older = rotated(&self.path, index);
+            if older.exists() {
+                fs::rename(older, rotated(&self.path, index + 1))?;
+            }
+        }
+        fs::rename(&self.path, rotated(&self.path, 1))?;
+        *self = Self::open(self.path.clone(), self.max_size)?;
+        Ok(())
+    }
+}
+
+fn rotated(path: &Path, index: usize) -> PathBuf {
+    let mut name = OsString::from(path);
+    name.push(format!(".{}", index));
+    name.into()
+}
+
+/// UTC time as `YYYY-MM-DD hh:mm:ss.mmm`
+pub(crate) fn timestamp(time: SystemTime) -> String {
+    let elapsed = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = elapsed.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    // Civil date from days since the epoch, by Howard Hinnant
+    let days = (secs / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03}",
+        year,
+        month,
+        day,
+        hours,
+        minutes,
+        seconds,
+        elapsed.subsec_millis()
+    )
+}
+
+#[cfg(not(target_os = "windows"))]
+pub(crate) fn xdg_state_home() -> PathBuf {
+    match env::var("XDG_STATE_HOME") {
+        Ok(state_home) => PathBuf::from(state_home),
+        _ => {
+            let home = env::var("HOME").unwrap_or_default();
+            Path::new(&home).join(".local").join("state")
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn xdg_state_home() -> PathBuf {
+    PathBuf::from(env::var("LOCALAPPDATA").unwrap_or_default())
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn it_should_format_utc_timestamps() {
+        assert_eq!(timestamp(UNIX_EPOCH), "1970-01-01 00:00:00.000");
+        let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_250);
+        assert_eq!(timestamp(time), "2023-11-14 22:13:20.250");
+        let leap = UNIX_EPOCH + Duration::from_secs(951_825_600);
+        assert_eq!(timestamp(leap), "2000-02-29 12:00:00.000");
+    }
+
+    #[test]
+    fn it_should_rotate_files() {
+        let dir = env::temp_dir().join(format!("rscenes-logger-{}", std::process::id()));
+        let path = dir.join("game.log");
+        let mut file = LogFile::open(path.clone(), 16).unwrap();
+        for line in [
+            "first line",
+            "second line",
+            "third line",
+            "fourth line",
+            "last",
+        ] {
+            file.write_line(line).unwrap();
+        }
+        let read = |path: &Path| fs::read_to_string(path).unwrap();
+        let contents = (
+            read(&path),
+            read(&rotated(&path, 1)),
+            read(&rotated(&path, 2)),
+            read(&rotated(&path, 3)),
+        );
+        let dropped = rotated(&path, 4).exists();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            contents,
+            (
+                "last\n".to_owned(),
+                "fourth line\n".to_owned(),
+                "third line\n".to_owned(),
+                "second line\n".to_owned(),
+            )
+        );
+        assert!(!dropped);
+    }
+}
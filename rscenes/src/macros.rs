@@ -2,30 +2,40 @@
 #[macro_export]
 macro_rules! setup {
     (|$con:ident| { $($stmt:expr)* }) => {
-        |$con: $crate::prelude::PlainConnector| -> Result<(), String> {
+        |$con: $crate::prelude::PlainConnector| -> Result<(), $crate::Error> {
             $($stmt)*
             Ok(())
         }
     };
 
     (|$con:ident| $stmt:expr) => {
-        |$con: $crate::prelude::PlainConnector| -> Result<(), String> {
+        |$con: $crate::prelude::PlainConnector| -> Result<(), $crate::Error> {
             $stmt;
             Ok(())
         }
     };
 
     (move |$con:ident| { $($stmt:expr)* }) => {
-        move |$con: $crate::prelude::PlainConnector| -> Result<(), String> {
+        move |$con: $crate::prelude::PlainConnector| -> Result<(), $crate::Error> {
             $($stmt)*
             Ok(())
         }
     };
 
     (move |$con:ident| $stmt:expr) => {
-        move |$con: $crate::prelude::PlainConnector| -> Result<(), String> {
+        move |$con: $crate::prelude::PlainConnector| -> Result<(), $crate::Error> {
             $stmt;
             Ok(())
         }
     };
 }
+
+/// Log an error the game can't recover from and exit, as raylib does on fatal messages
+macro_rules! fatal {
+    ($($arg:tt)+) => {{
+        log::error!($($arg)+);
+        log::logger().flush();
+        std::process::exit(1)
+    }};
+}
+pub(crate) use fatal;
@@ -1,19 +1,69 @@
-use crate::{connectors::*, scene::Scene, state::State};
-use resolution::current_resolution;
-use rscenes_raylib_connector::{
-    assets::{TraceLogLevel, TraceLogLevelExt},
-    interface::*,
+use crate::{
+    cli::{self, Options},
+    config::Config,
+    connectors::*,
+    crash::{self, Crash, CrashScene, ErrorPolicy},
+    error::{report, Error},
+    macros::fatal,
+    overlay::{DebugOverlay, Timings},
+    profiler::{self, Profiler},
+    replay::{Replay, Session},
+    scene::{Payload, Scene, SceneHandle},
+    state::State,
+    timestep::{self, FixedTimestep},
+    transition::ActiveTransition,
 };
+use resolution::current_resolution;
+use rscenes_raylib_connector::{assets::Vector2, interface::*};
+use std::{fmt, mem, time::Instant};
 
 /// Control the game
 #[derive(Default)]
 pub struct Rscenes {
+    /// Window and runtime settings, applied on start
+    pub config: Config,
     /// Change this to set the window title
+    #[deprecated(note = "set config.title instead")]
     pub title: String,
-    /// change this to set window startup geometry
+    /// Change this to set window startup geometry
+    #[deprecated(note = "set config.size instead")]
     pub window_size: (i32, i32),
+    /// Set this to run Scene::on_fixed_update() with a constant step
+    pub fixed_timestep: Option<FixedTimestep>,
+    /// Set this to record the session input or play it back
+    pub replay: Option<Replay>,
+    /// Set this to reload the changed asset files of the scenes every given seconds, for
+    /// development
+    pub hot_reload: Option<f32>,
+    /// Set this to have a key toggle the debug overlay
+    pub debug_overlay: Option<DebugOverlay>,
+    /// Set this to record the time taken by the scene callbacks, frame by frame
+    pub profiler: Option<Profiler>,
+    /// What to do when a scene fails, see [`ErrorPolicy`]
+    pub error_policy: ErrorPolicy,
+    options: Options,
+    session: Option<Session>,
     setups: Vec<Box<dyn SetupCallback>>,
-    scenes: Vec<Box<dyn Scene>>,
+    scenes: Vec<StackedScene>,
+    last_handle: u64,
+}
+
+/// Failed scene callback
+struct Failure {
+    /// What the scene was doing, like `updating Gameplay #2 scene`
+    context: String,
+    /// The failing scene, frozen ones below an overlay can fail too
+    handle: SceneHandle,
+    /// Whether the scene failed to set up or load, never getting ready
+    unready: bool,
+    err: Error,
+}
+
+/// Scene in the stack along with its tracking data
+struct StackedScene {
+    handle: SceneHandle,
+    scene: Box<dyn Scene>,
+    set_up: bool,
 }
 
 impl Rscenes {
@@ -25,120 +75,504 @@ impl Rscenes {
 
     /// Add the initial scene
     pub fn set_init(&mut self, scene: Box<dyn Scene>) -> &mut Self {
-        self.scenes.push(scene);
+        self.push_scene(scene);
+        self
+    }
+
+    /// Parse the standard command-line options, see [`Options`]
+    ///
+    /// Call it after setting the config, so the arguments take precedence.
+    pub fn parse_args(&mut self) -> Result<&mut Self, Error> {
+        Ok(self.set_options(Options::from_args()?))
+    }
+
+    /// Override the config and replay settings with the given options, and keep them for the
+    /// scenes to query
+    pub fn set_options(&mut self, options: Options) -> &mut Self {
+        options.apply(&mut self.config, &mut self.replay);
+        self.options = options;
         self
     }
 
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Find the topmost scene in the stack with the given name
+    pub fn find_scene(&self, name: &str) -> Option<SceneHandle> {
+        self.scenes
+            .iter()
+            .rev()
+            .find(|stacked| stacked.scene.name() == Some(name))
+            .map(|stacked| stacked.handle)
+    }
+
     /// Start mainloop
     pub fn start(&mut self) {
+        self.config.init_logging(PlainConnector);
+        crash::install_panic_hook();
         if self.scenes.is_empty() {
-            TraceLogLevel::Fatal.log("no initial scene supplied");
+            fatal!("no initial scene supplied");
         }
         if let Err(err) = self.setup() {
-            TraceLogLevel::Fatal.log(format!("loading setup: {:?}", err));
+            fatal!("loading setup: {}", report(&err));
+        }
+        if let Some(name) = self.options.start_scene.clone() {
+            self.start_from(&name);
         }
 
         let mut reloaded = true;
         let plain_connector = PlainConnector;
-        let connector_3d = Connector3D;
-        let connector_2d = Connector2D;
-        let mut track_loaded_scenes: Vec<usize> = Vec::with_capacity(self.scenes.len() + 1);
+        let mut accumulator = 0.0_f32;
+        let mut transition: Option<ActiveTransition> = None;
+        let mut resumed: Option<Option<Payload>> = None;
+        let mut focused = plain_connector.is_window_focused();
+        let mut last_reload = Instant::now();
+        let mut failure: Option<Failure> = None;
 
         'mainloop: while !plain_connector.window_should_close() {
-            let scene = match self.scenes.last_mut() {
-                Some(scene) => scene,
+            Profiler::begin_frame();
+            if let Some(session) = self.session.as_mut() {
+                session.next_frame(plain_connector);
+            }
+            if let Some(overlay) = self.debug_overlay.as_mut() {
+                if plain_connector.is_key_pressed(overlay.toggle_key) {
+                    overlay.visible = !overlay.visible;
+                }
+            }
+            if plain_connector.is_window_resized() {
+                let size = Vector2 {
+                    x: plain_connector.get_render_width() as f32,
+                    y: plain_connector.get_render_height() as f32,
+                };
+                for stacked in self.scenes.iter_mut() {
+                    let res = stacked
+                        .profiled("on_resize", |scene| scene.on_resize(plain_connector, size));
+                    check(res, "resizing", stacked, &mut failure);
+                }
+            }
+            // Scenes covered by an overlay hear about it too, like about resizes
+            if plain_connector.is_window_focused() != focused {
+                focused = !focused;
+                for stacked in self.scenes.iter_mut() {
+                    let res = stacked.profiled("on_focus_changed", |scene| {
+                        scene.on_focus_changed(plain_connector, focused)
+                    });
+                    check(res, "focusing", stacked, &mut failure);
+                }
+            }
+
+            if let Some(interval) = self.hot_reload {
+                if last_reload.elapsed().as_secs_f32() >= interval {
+                    last_reload = Instant::now();
+                    self.reload_assets(plain_connector);
+                }
+            }
+
+            let stacked = match self.scenes.last_mut() {
+                Some(stacked) => stacked,
                 None => break 'mainloop,
             };
 
-            if reloaded {
-                let scene_id = scene.id();
-                let first_load = !track_loaded_scenes.contains(&scene_id);
+            if reloaded && failure.is_none() {
+                let first_load = !stacked.set_up;
 
                 if first_load {
-                    track_loaded_scenes.push(scene_id);
-                    if let Err(err) = scene.on_setup(plain_connector) {
-                        TraceLogLevel::Fatal
-                            .log(format!("setting {:?} scene up: {:?}", scene, err));
+                    stacked.set_up = true;
+                    if let Err(err) =
+                        stacked.profiled("on_setup", |scene| scene.on_setup(plain_connector))
+                    {
+                        failure = Some(Failure::new("setting up", stacked, err).unready());
                     }
                 }
 
-                if let Err(err) = scene.on_load(plain_connector) {
-                    if first_load {
-                        TraceLogLevel::Fatal
-                    } else {
-                        TraceLogLevel::Error
+                if failure.is_none() {
+                    if let Err(err) =
+                        stacked.profiled("on_load", |scene| scene.on_load(plain_connector))
+                    {
+                        // A scene failing to reload can still go on with what it had
+                        if first_load || matches!(err, Error::Panic(_)) {
+                            failure = Some(Failure::new("loading", stacked, err).unready());
+                        } else {
+                            log::error!("reloading {} scene: {}", stacked, report(&err));
+                        }
+                    }
+                }
+
+                if failure.is_none() {
+                    if let Some(payload) = resumed.take() {
+                        let res = stacked.profiled("on_resume", |scene| {
+                            scene.on_resume(plain_connector, payload)
+                        });
+                        check(res, "resuming", stacked, &mut failure);
                     }
-                    .log(format!("reloading {:?} scene: {:?}", scene, err));
                 }
 
                 reloaded = false;
             }
 
             plain_connector.begin_drawing();
-            match scene.on_update(plain_connector, plain_connector.get_frame_time()) {
-                Ok(State::Keep) => {
-                    if let Err(err) = scene.draw_2d(connector_2d) {
-                        TraceLogLevel::Error
-                            .log(format!("drawing shapes (2D): {:?} {:?}", scene, err));
+            let dt = match &self.session {
+                Some(session) => session.frame_time(),
+                None => plain_connector.get_frame_time(),
+            };
+            if failure.is_none() {
+                let res = stacked.profiled("tweens", |scene| {
+                    if let Some(tweens) = scene.tweens() {
+                        tweens.advance(dt);
                     }
-                    if let Err(err) = scene.draw_3d(connector_3d) {
-                        TraceLogLevel::Error
-                            .log(format!("drawing models (3D): {:?} {:?}", scene, err));
+                    Ok(())
+                });
+                check(res, "animating", stacked, &mut failure);
+            }
+            if failure.is_none() {
+                let res = stacked.profiled("scheduler", |scene| {
+                    if let Some(scheduler) = scene.scheduler() {
+                        scheduler.advance(plain_connector, dt);
                     }
-                    if let Err(err) = scene.draw_hud(connector_2d) {
-                        TraceLogLevel::Error
-                            .log(format!("drawing HUD (2D): {:?} {:?}", scene, err));
+                    Ok(())
+                });
+                check(res, "running the tasks of", stacked, &mut failure);
+            }
+            let mut timings = Timings::default();
+            let update_start = Instant::now();
+            let mut state = Ok(State::Keep);
+            if failure.is_none() {
+                if let Some(fixed_timestep) = self.fixed_timestep {
+                    for _ in 0..fixed_timestep.advance(&mut accumulator, dt) {
+                        state = stacked.profiled("on_fixed_update", |scene| {
+                            scene.on_fixed_update(plain_connector, fixed_timestep.step)
+                        });
+                        if !matches!(state, Ok(State::Keep)) {
+                            break;
+                        }
                     }
+                    timestep::set_interpolation_alpha(fixed_timestep.alpha(accumulator));
                 }
-
-                Ok(State::Next(next_scene)) => {
-                    {
-                        if let Err(err) = scene.on_exit(plain_connector) {
-                            TraceLogLevel::Error
-                                .log(format!("exiting {:?} scene: {:?}", scene, err));
+                if let Ok(State::Keep) = state {
+                    state =
+                        stacked.profiled("on_update", |scene| scene.on_update(plain_connector, dt));
+                }
+            }
+            timings.update = update_start.elapsed();
+            // A scene failing to set up or load never got ready to be paused
+            let unready = failure.as_ref().is_some_and(|failure| failure.unready);
+            let policy = self.error_policy;
+            let state = match (failure.take(), state) {
+                (Some(failure), _) => self.apply_error_policy(failure),
+                (None, Ok(state)) => state,
+                (None, Err(err))
+                    if policy == ErrorPolicy::Abort && !matches!(err, Error::Panic(_)) =>
+                {
+                    log::error!("updating {} scene: {}", stacked, report(&err));
+                    State::Keep
+                }
+                (None, Err(err)) => {
+                    let failure = Failure::new("updating", stacked, err);
+                    self.apply_error_policy(failure)
+                }
+            };
+            let state = match state {
+                // Already on top, there's nothing to pop nor resume
+                State::PopTo(matcher)
+                    if self
+                        .scenes
+                        .last()
+                        .is_some_and(|top| matcher.matches(top.scene.as_ref())) =>
+                {
+                    State::Keep
+                }
+                state => state,
+            };
+            match state {
+                State::Keep => {
+                    let mut draw = || draw_stack(&self.scenes, &mut timings, &mut failure);
+                    match transition.as_mut() {
+                        Some(active) => {
+                            if active.render(dt, draw) {
+                                if let Some(active) = transition.take() {
+                                    active.unload();
+                                }
+                            }
                         }
+                        None => draw(),
                     }
-                    self.scenes.push(next_scene);
+                    self.draw_overlay(plain_connector);
+                }
+
+                State::Next(next_scene) => {
+                    if let Some(stacked) = self.scenes.last_mut().filter(|_| !unready) {
+                        pause_scene(stacked, plain_connector);
+                    }
+                    self.push_scene(next_scene);
+                    accumulator = 0.0;
                     reloaded = true;
                 }
 
-                Ok(State::Prev(count)) => {
-                    for _ in 0..count {
-                        if let Some(mut scene) = self.scenes.pop() {
-                            if let Err(err) = scene.on_exit(plain_connector) {
-                                TraceLogLevel::Error
-                                    .log(format!("exiting {:?} scene: {:?}", scene, err));
-                            }
+                State::NextWith(next_scene, next_transition) => {
+                    let active = ActiveTransition::new(next_transition);
+                    active.capture(|| draw_stack(&self.scenes, &mut timings, &mut failure));
+                    if let Some(previous) = transition.replace(active) {
+                        previous.unload();
+                    }
+                    if let Some(stacked) = self.scenes.last_mut() {
+                        pause_scene(stacked, plain_connector);
+                    }
+                    self.push_scene(next_scene);
+                    accumulator = 0.0;
+                    reloaded = true;
+                }
+
+                State::Prev(count) => {
+                    resumed = Some(self.pop_scenes(count, plain_connector));
+                    accumulator = 0.0;
+                    reloaded = true;
+                }
+
+                State::PrevWith(count, next_transition) => {
+                    let active = ActiveTransition::new(next_transition);
+                    active.capture(|| draw_stack(&self.scenes, &mut timings, &mut failure));
+                    if let Some(previous) = transition.replace(active) {
+                        previous.unload();
+                    }
+                    resumed = Some(self.pop_scenes(count, plain_connector));
+                    accumulator = 0.0;
+                    reloaded = true;
+                }
+
+                State::Replace(next_scene) => {
+                    self.pop_scenes(1, plain_connector);
+                    self.push_scene(next_scene);
+                    accumulator = 0.0;
+                    reloaded = true;
+                }
+
+                State::PopTo(matcher) => {
+                    match self
+                        .scenes
+                        .iter()
+                        .rposition(|stacked| matcher.matches(stacked.scene.as_ref()))
+                    {
+                        Some(index) => {
+                            let count = self.scenes.len() - index - 1;
+                            resumed = Some(self.pop_scenes(count, plain_connector));
+                            accumulator = 0.0;
+                            reloaded = true;
                         }
+                        None => log::error!("no scene in the stack matches {:?}", matcher),
                     }
+                }
+
+                State::PopToRoot => {
+                    let count = self.scenes.len().saturating_sub(1);
+                    resumed = Some(self.pop_scenes(count, plain_connector));
+                    accumulator = 0.0;
+                    reloaded = true;
+                }
+
+                State::Reset(next_scene) => {
+                    self.pop_scenes(self.scenes.len(), plain_connector);
+                    self.push_scene(next_scene);
+                    accumulator = 0.0;
                     reloaded = true;
                 }
 
-                Ok(State::Quit) => {
-                    if let Err(err) = scene.on_exit(plain_connector) {
-                        TraceLogLevel::Error.log(format!("exiting {:?} scene: {:?}", scene, err));
+                State::Quit => {
+                    if let Some(stacked) = self.scenes.last_mut() {
+                        exit_scene(stacked, plain_connector);
                     }
-                    plain_connector.close_window();
                     break;
                 }
+            }
+            if let Some(overlay) = self.debug_overlay.as_mut() {
+                overlay.record(dt, &timings);
+            }
+            {
+                let _scope = Profiler::scope("end_drawing");
+                plain_connector.end_drawing();
+            }
+            Profiler::end_frame();
+        }
+
+        if let Some(active) = transition {
+            active.unload();
+        }
+        while let Some(mut stacked) = self.scenes.pop() {
+            teardown_scene(&mut stacked, plain_connector);
+        }
+        if let Some(session) = self.session.take() {
+            if let Err(err) = session.finish(plain_connector) {
+                log::error!("saving input session: {}", report(&err));
+            }
+        }
+        if let Some(profiler) = &self.profiler {
+            Profiler::end_frame();
+            if let Err(err) = profiler.stop() {
+                log::error!("saving profiling trace: {}", report(&err));
+            }
+        }
+        // Scenes are gone along with their assets, the context can go now
+        if self.config.audio {
+            plain_connector.close_audio_device();
+        }
+        plain_connector.close_window();
+    }
+
+    /// Drop the initial scenes stacked over the named one, none of them is set up yet
+    fn start_from(&mut self, name: &str) {
+        match self
+            .scenes
+            .iter()
+            .rposition(|stacked| stacked.scene.name() == Some(name))
+        {
+            Some(index) => self.scenes.truncate(index + 1),
+            None => log::info!("no initial scene named {}, leaving it to the game", name),
+        }
+    }
 
+    /// Report a scene failure and turn it into the next state, following the error policy
+    fn apply_error_policy(&self, failure: Failure) -> State {
+        let Failure {
+            context,
+            handle,
+            err,
+            ..
+        } = failure;
+        // The failing scene and the ones above it
+        let depth = self
+            .scenes
+            .iter()
+            .rev()
+            .position(|stacked| stacked.handle == handle)
+            .map_or(1, |index| index + 1);
+        let stack = self.scenes.iter().rev().map(ToString::to_string).collect();
+        let crash = Crash::new(context.clone(), &err, stack);
+        let path = self
+            .config
+            .crash_dir
+            .as_ref()
+            .and_then(|dir| match crash.save(dir) {
+                Ok(path) => Some(path),
                 Err(err) => {
-                    TraceLogLevel::Error.log(format!("updating {:?} scene: {}", scene, err))
+                    log::error!("saving crash report: {}", report(&err));
+                    None
+                }
+            });
+        if let Some(path) = &path {
+            log::info!("crash report saved into {}", path.display());
+        }
+        match self.error_policy {
+            ErrorPolicy::Abort => fatal!("{}: {}", context, report(&err)),
+            ErrorPolicy::Skip => {
+                log::error!("{}, skipping it: {}", context, report(&err));
+                State::Prev(depth)
+            }
+            ErrorPolicy::CrashScreen => {
+                log::error!("{}: {}", context, report(&err));
+                State::Next(Box::new(CrashScene::new(crash, path, depth)))
+            }
+        }
+    }
+
+    /// Draw the debug overlay when shown, over everything else
+    fn draw_overlay(&mut self, connector: PlainConnector) {
+        let overlay = match self.debug_overlay.as_mut() {
+            Some(overlay) if overlay.visible => overlay,
+            _ => return,
+        };
+        let stack = self
+            .scenes
+            .iter_mut()
+            .map(|stacked| {
+                let assets = stacked.scene.assets().map(|assets| assets.len());
+                let label = format!("{} {:?}", stacked.handle, stacked.scene);
+                (label, assets.unwrap_or_default())
+            })
+            .collect();
+        let mouse = connector.get_mouse_position();
+        let world = match self.scenes.last() {
+            Some(stacked) => connector.get_screen_to_world_2d(mouse, stacked.scene.get_camera_2d()),
+            None => mouse,
+        };
+        overlay.inspect(stack, mouse, world);
+        overlay.draw(Connector2D);
+    }
+
+    /// Reload the changed assets of every scene in the stack, frozen ones are still drawn
+    fn reload_assets(&mut self, connector: PlainConnector) {
+        for stacked in self.scenes.iter_mut() {
+            let reloaded = stacked
+                .scene
+                .assets()
+                .map(|assets| assets.reload_changed())
+                .unwrap_or_default();
+            if reloaded {
+                let res = stacked.profiled("on_assets_reloaded", |scene| {
+                    scene.on_assets_reloaded(connector)
+                });
+                log_error(res, "reloading assets for", stacked);
+            }
+        }
+    }
+
+    /// Push a scene into the stack with a brand new handle
+    fn push_scene(&mut self, scene: Box<dyn Scene>) {
+        self.last_handle += 1;
+        self.scenes.push(StackedScene {
+            handle: SceneHandle(self.last_handle),
+            scene,
+            set_up: false,
+        });
+    }
+
+    /// Drop scenes from the top of the stack, exiting each of them, and return the first payload
+    /// left by them
+    fn pop_scenes(&mut self, count: usize, connector: PlainConnector) -> Option<Payload> {
+        let mut payload = None;
+        for _ in 0..count {
+            if let Some(mut stacked) = self.scenes.pop() {
+                exit_scene(&mut stacked, connector);
+                if payload.is_none() {
+                    payload = stacked.scene.take_payload();
                 }
+                teardown_scene(&mut stacked, connector);
             }
-            plain_connector.end_drawing();
         }
+        payload
     }
 
     pub fn screen_size(&self) -> (i32, i32) {
         current_resolution().unwrap_or((800, 600))
     }
 
-    fn setup(&mut self) -> Result<(), String> {
+    /// Move the window fields from before Config into it, the command-line options still
+    /// overriding them
+    #[allow(deprecated)]
+    fn apply_deprecated_fields(&mut self) {
+        let title = mem::take(&mut self.title);
+        let size = mem::take(&mut self.window_size);
+        if title.is_empty() && size == (0, 0) {
+            return;
+        }
+        if !title.is_empty() {
+            self.config.title = title;
+        }
+        if size != (0, 0) {
+            self.config.size = size;
+        }
+        self.options.apply(&mut self.config, &mut self.replay);
+    }
+
+    fn setup(&mut self) -> Result<(), Error> {
         let connector = PlainConnector;
 
-        connector.set_target_fps(60);
-        let (width, height) = match self.window_size {
+        self.apply_deprecated_fields();
+        cli::set_options(self.options.clone());
+        if let Some(profiler) = &self.profiler {
+            profiler.start();
+        }
+        self.config.apply_before_init(connector);
+        let (width, height) = match self.config.size {
             (0, 0) => self.screen_size(),
             (0, height) => {
                 let (width, _) = self.screen_size();
@@ -150,7 +584,11 @@ impl Rscenes {
             }
             (width, height) => (width, height),
         };
-        connector.init_window(width, height, &self.title);
+        connector.init_window(width, height, &self.config.title);
+        self.config.apply_after_init(connector);
+        if let Some(replay) = &self.replay {
+            self.session = Some(Session::start(replay, self.config.fps, connector)?);
+        }
         for callback in self.setups.iter() {
             callback(connector)?;
         }
@@ -159,4 +597,1006 @@ impl Rscenes {
     }
 }
 
-pub trait SetupCallback = Fn(PlainConnector) -> Result<(), String> + 'static;
+impl StackedScene {
+    /// Run a scene callback within a profiling scope named after it, catching its panics
+    fn profiled<T>(
+        &mut self,
+        callback: &str,
+        f: impl FnOnce(&mut dyn Scene) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let _scope = profiler::scene_scope(self, callback);
+        let scene = self.scene.as_mut();
+        crash::catch(|| f(scene))
+    }
+}
+
+impl Failure {
+    fn new(action: &str, stacked: &StackedScene, err: Error) -> Self {
+        Self {
+            context: format!("{} {} scene", action, stacked),
+            handle: stacked.handle,
+            unready: false,
+            err,
+        }
+    }
+
+    fn unready(mut self) -> Self {
+        self.unready = true;
+        self
+    }
+}
+
+impl fmt::Display for StackedScene {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.scene.name() {
+            return write!(f, "{} {}", name, self.handle);
+        }
+        // Without the module path, the generic arguments kept as they are
+        let name = self.scene.type_name();
+        let (path, args) = name.split_at(name.find('<').unwrap_or(name.len()));
+        let tpe = path.rsplit("::").next().unwrap_or(path);
+        write!(f, "{}{} {}", tpe, args, self.handle)
+    }
+}
+
+/// Draw the topmost scene, and the scenes below it while it's an overlay
+fn draw_stack(scenes: &[StackedScene], timings: &mut Timings, failure: &mut Option<Failure>) {
+    let base = scenes
+        .iter()
+        .rposition(|stacked| !stacked.scene.is_overlay())
+        .unwrap_or_default();
+    for stacked in scenes[base..].iter() {
+        draw_scene(stacked, timings, failure);
+    }
+}
+
+fn draw_scene(stacked: &StackedScene, timings: &mut Timings, failure: &mut Option<Failure>) {
+    let scope = profiler::scene_scope(stacked, "draw_2d");
+    let start = Instant::now();
+    let res = crash::catch(|| stacked.scene.draw_2d(Connector2D));
+    check(res, "drawing shapes (2D) of", stacked, failure);
+    timings.draw_2d += start.elapsed();
+    drop(scope);
+    let scope = profiler::scene_scope(stacked, "draw_3d");
+    let start = Instant::now();
+    let res = crash::catch(|| stacked.scene.draw_3d(Connector3D));
+    check(res, "drawing models (3D) of", stacked, failure);
+    timings.draw_3d += start.elapsed();
+    drop(scope);
+    let _scope = profiler::scene_scope(stacked, "draw_hud");
+    let start = Instant::now();
+    let res = crash::catch(|| stacked.scene.draw_hud(Connector2D));
+    check(res, "drawing HUD (2D) of", stacked, failure);
+    timings.draw_hud += start.elapsed();
+}
+
+/// Another scene is being pushed over this one
+fn pause_scene(stacked: &mut StackedScene, connector: PlainConnector) {
+    // Unlike exit_scene(), scheduled tasks are kept for when the scene resumes
+    let res = stacked.profiled("on_exit", |scene| scene.on_exit(connector));
+    log_error(res, "exiting", stacked);
+    let res = stacked.profiled("on_pause", |scene| scene.on_pause(connector));
+    log_error(res, "pausing", stacked);
+}
+
+fn exit_scene(stacked: &mut StackedScene, connector: PlainConnector) {
+    let res = stacked.profiled("on_exit", |scene| scene.on_exit(connector));
+    log_error(res, "exiting", stacked);
+    if let Some(scheduler) = stacked.scene.scheduler() {
+        scheduler.clear();
+    }
+}
+
+fn teardown_scene(stacked: &mut StackedScene, connector: PlainConnector) {
+    let res = stacked.profiled("on_teardown", |scene| scene.on_teardown(connector));
+    log_error(res, "tearing down", stacked);
+}
+
+fn log_error(res: Result<(), Error>, action: &str, stacked: &StackedScene) {
+    if let Err(err) = res {
+        log::error!("{} {} scene: {}", action, stacked, report(&err));
+    }
+}
+
+/// Log the errors of a scene still on screen, leaving its panics to the error policy
+fn check(
+    res: Result<(), Error>,
+    action: &str,
+    stacked: &StackedScene,
+    failure: &mut Option<Failure>,
+) {
+    match res {
+        Err(err @ Error::Panic(_)) if failure.is_none() => {
+            *failure = Some(Failure::new(action, stacked, err));
+        }
+        res => log_error(res, action, stacked),
+    }
+}
+
+pub trait SetupCallback = Fn(PlainConnector) -> Result<(), Error> + 'static;
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(all(test, feature = "headless"))]
+mod tests {
+    use super::*;
+    use crate::{
+        extras::Headless,
+        scheduler::Scheduler,
+        state::SceneMatcher,
+        transition::Transition,
+        tween::{Tween, Tweened, Tweens},
+    };
+    use rscenes_raylib_connector::assets::{Color, ColorExt, KeyboardKey};
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    #[derive(Debug, Default)]
+    struct Calls {
+        fixed: Vec<f32>,
+        updates: usize,
+        alpha: Vec<f32>,
+    }
+
+    #[derive(Debug)]
+    struct Probe(Rc<RefCell<Calls>>);
+
+    impl Scene for Probe {
+        fn on_fixed_update(&mut self, _: PlainConnector, step: f32) -> Result<State, Error> {
+            self.0.borrow_mut().fixed.push(step);
+            Ok(State::Keep)
+        }
+
+        fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+            self.0.borrow_mut().updates += 1;
+            Ok(State::Keep)
+        }
+
+        fn draw_2d(&self, connector: Connector2D) -> Result<(), Error> {
+            let alpha = connector.get_interpolation_alpha();
+            self.0.borrow_mut().alpha.push(alpha);
+            Ok(())
+        }
+    }
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    #[derive(Debug)]
+    struct Scripted {
+        name: &'static str,
+        log: Log,
+        script: Option<State>,
+        payload: Option<i32>,
+    }
+
+    impl Scene for Scripted {
+        fn name(&self) -> Option<&str> {
+            Some(self.name)
+        }
+
+        fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+            self.log.borrow_mut().push(format!("update {}", self.name));
+            Ok(self.script.take().unwrap_or(State::Keep))
+        }
+
+        fn on_resume(&mut self, _: PlainConnector, payload: Option<Payload>) -> Result<(), Error> {
+            let payload = payload.and_then(|payload| payload.downcast::<i32>().ok());
+            self.log
+                .borrow_mut()
+                .push(format!("resume {} {:?}", self.name, payload));
+            Ok(())
+        }
+
+        fn take_payload(&mut self) -> Option<Payload> {
+            self.payload
+                .take()
+                .map(|payload| Box::new(payload) as Payload)
+        }
+
+        fn on_pause(&mut self, _: PlainConnector) -> Result<(), Error> {
+            self.log.borrow_mut().push(format!("pause {}", self.name));
+            Ok(())
+        }
+
+        fn on_resize(&mut self, _: PlainConnector, size: Vector2) -> Result<(), Error> {
+            self.log
+                .borrow_mut()
+                .push(format!("resize {} {}x{}", self.name, size.x, size.y));
+            Ok(())
+        }
+
+        fn on_focus_changed(&mut self, _: PlainConnector, focused: bool) -> Result<(), Error> {
+            self.log
+                .borrow_mut()
+                .push(format!("focus {} {}", self.name, focused));
+            Ok(())
+        }
+
+        fn on_teardown(&mut self, _: PlainConnector) -> Result<(), Error> {
+            self.log
+                .borrow_mut()
+                .push(format!("teardown {}", self.name));
+            Ok(())
+        }
+
+        fn on_exit(&mut self, _: PlainConnector) -> Result<(), Error> {
+            self.log.borrow_mut().push(format!("exit {}", self.name));
+            Ok(())
+        }
+    }
+
+    fn scripted(name: &'static str, log: &Log, script: Option<State>) -> Box<dyn Scene> {
+        Box::new(Scripted {
+            name,
+            log: log.clone(),
+            script,
+            payload: None,
+        })
+    }
+
+    /// Manager for an 800x600 headless window, closing after the given number of frames
+    fn test_manager(frames: u64) -> Rscenes {
+        Headless::reset();
+        Headless::close_after(frames);
+        Rscenes {
+            config: Config {
+                size: (800, 600),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn run_scripted(
+        mut manager: Rscenes,
+        root: impl FnOnce(&Log) -> Box<dyn Scene>,
+    ) -> Vec<String> {
+        let log = Log::default();
+        manager.set_init(root(&log)).start();
+        log.take()
+    }
+
+    #[derive(Debug)]
+    struct Layer {
+        name: &'static str,
+        overlay: bool,
+        log: Log,
+    }
+
+    impl Scene for Layer {
+        fn is_overlay(&self) -> bool {
+            self.overlay
+        }
+
+        fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+            self.log.borrow_mut().push(format!("update {}", self.name));
+            Ok(State::Keep)
+        }
+
+        fn draw_2d(&self, _: Connector2D) -> Result<(), Error> {
+            self.log.borrow_mut().push(format!("draw {}", self.name));
+            Ok(())
+        }
+    }
+
+    fn run_layers(layers: &[(&'static str, bool)]) -> Vec<String> {
+        let log = Log::default();
+        let mut manager = test_manager(1);
+        for (name, overlay) in layers {
+            manager.set_init(Box::new(Layer {
+                name,
+                overlay: *overlay,
+                log: log.clone(),
+            }));
+        }
+        manager.start();
+        log.take()
+    }
+
+    fn run(fixed_timestep: Option<FixedTimestep>, frames: u64) -> Calls {
+        let mut manager = test_manager(frames);
+        Headless::set_frame_time(0.05);
+        manager.fixed_timestep = fixed_timestep;
+        let calls = Rc::new(RefCell::new(Calls::default()));
+        manager.set_init(Box::new(Probe(calls.clone()))).start();
+        timestep::set_interpolation_alpha(1.0);
+        calls.take()
+    }
+
+    #[test]
+    fn it_should_only_run_variable_update_by_default() {
+        let calls = run(None, 3);
+        assert!(calls.fixed.is_empty());
+        assert_eq!(calls.updates, 3);
+        assert_eq!(calls.alpha, vec![1.0; 3]);
+    }
+
+    #[test]
+    fn it_should_run_fixed_updates_with_constant_step() {
+        let calls = run(Some(FixedTimestep::new(0.025)), 3);
+        assert_eq!(calls.fixed, vec![0.025; 6]);
+        assert_eq!(calls.updates, 3);
+    }
+
+    #[test]
+    fn it_should_expose_interpolation_alpha() {
+        let calls = run(Some(FixedTimestep::new(0.04)), 2);
+        assert_eq!(calls.fixed.len(), 2);
+        assert!((calls.alpha[0] - 0.25).abs() < 1e-4);
+        assert!((calls.alpha[1] - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn it_should_replace_current_scene() {
+        let log = run_scripted(test_manager(4), |log| {
+            let c = scripted("c", log, None);
+            let b = scripted("b", log, Some(State::Replace(c)));
+            scripted("a", log, Some(State::Next(b)))
+        });
+        assert_eq!(
+            log,
+            vec![
+                "update a",
+                "exit a",
+                "pause a",
+                "update b",
+                "exit b",
+                "teardown b",
+                "update c",
+                "update c",
+                "teardown c",
+                "teardown a"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_the_top_scene_when_popping_to_it() {
+        let log = run_scripted(test_manager(2), |log| {
+            scripted("a", log, Some(State::PopTo("a".into())))
+        });
+        assert_eq!(log, vec!["update a", "update a", "teardown a"]);
+    }
+
+    #[test]
+    fn it_should_pop_to_named_scene() {
+        let log = run_scripted(test_manager(4), |log| {
+            let c = scripted("c", log, Some(State::PopTo("a".into())));
+            let b = scripted("b", log, Some(State::Next(c)));
+            scripted("a", log, Some(State::Next(b)))
+        });
+        assert_eq!(
+            log,
+            vec![
+                "update a",
+                "exit a",
+                "pause a",
+                "update b",
+                "exit b",
+                "pause b",
+                "update c",
+                "exit c",
+                "teardown c",
+                "exit b",
+                "teardown b",
+                "resume a None",
+                "update a",
+                "teardown a"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_stack_when_no_scene_matches() {
+        let log = run_scripted(test_manager(3), |log| {
+            let matcher = SceneMatcher::predicate(|scene| scene.name().is_none());
+            let b = scripted("b", log, Some(State::PopTo(matcher)));
+            scripted("a", log, Some(State::Next(b)))
+        });
+        assert_eq!(
+            log,
+            vec![
+                "update a",
+                "exit a",
+                "pause a",
+                "update b",
+                "update b",
+                "teardown b",
+                "teardown a"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_pop_to_root() {
+        let log = run_scripted(test_manager(4), |log| {
+            let c = scripted("c", log, Some(State::PopToRoot));
+            let b = scripted("b", log, Some(State::Next(c)));
+            scripted("a", log, Some(State::Next(b)))
+        });
+        assert_eq!(
+            log,
+            vec![
+                "update a",
+                "exit a",
+                "pause a",
+                "update b",
+                "exit b",
+                "pause b",
+                "update c",
+                "exit c",
+                "teardown c",
+                "exit b",
+                "teardown b",
+                "resume a None",
+                "update a",
+                "teardown a"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_reset_stack() {
+        let log = run_scripted(test_manager(3), |log| {
+            let d = scripted("d", log, None);
+            let b = scripted("b", log, Some(State::Reset(d)));
+            scripted("a", log, Some(State::Next(b)))
+        });
+        assert_eq!(
+            log,
+            vec![
+                "update a",
+                "exit a",
+                "pause a",
+                "update b",
+                "exit b",
+                "teardown b",
+                "exit a",
+                "teardown a",
+                "update d",
+                "teardown d"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_play_transition_until_done() {
+        let manager = test_manager(4);
+        Headless::set_frame_time(0.05);
+        let log = run_scripted(manager, |log| {
+            let b = scripted("b", log, None);
+            scripted("a", log, Some(State::NextWith(b, Transition::Fade(0.1))))
+        });
+        assert_eq!(
+            log,
+            vec![
+                "update a",
+                "exit a",
+                "pause a",
+                "update b",
+                "update b",
+                "update b",
+                "teardown b",
+                "teardown a"
+            ]
+        );
+        let calls = Headless::take_draw_calls();
+        let count = |name: &str| calls.iter().filter(|call| call.name == name).count();
+        assert_eq!(count("load_render_texture"), 2);
+        assert_eq!(count("draw_texture_pro"), 4);
+        assert_eq!(count("unload_render_texture"), 2);
+    }
+
+    #[test]
+    fn it_should_draw_frozen_scenes_below_overlays() {
+        let log = run_layers(&[("a", false), ("b", false), ("c", true), ("d", true)]);
+        assert_eq!(log, vec!["update d", "draw b", "draw c", "draw d"]);
+    }
+
+    #[test]
+    fn it_should_draw_only_opaque_top_scene() {
+        let log = run_layers(&[("a", false), ("b", true), ("c", false)]);
+        assert_eq!(log, vec!["update c", "draw c"]);
+    }
+
+    #[test]
+    fn it_should_hand_payload_to_resumed_scene() {
+        let log = run_scripted(test_manager(3), |log| {
+            let b = Box::new(Scripted {
+                name: "b",
+                log: log.clone(),
+                script: Some(State::Prev(1)),
+                payload: Some(42),
+            });
+            scripted("a", log, Some(State::Next(b)))
+        });
+        assert_eq!(
+            log,
+            vec![
+                "update a",
+                "exit a",
+                "pause a",
+                "update b",
+                "exit b",
+                "teardown b",
+                "resume a Some(42)",
+                "update a",
+                "teardown a"
+            ]
+        );
+    }
+
+    #[derive(Debug)]
+    struct Window(Log);
+
+    impl Scene for Window {
+        fn is_overlay(&self) -> bool {
+            true
+        }
+
+        fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+            if Headless::frame() == 0 {
+                Headless::set_focused(false);
+                Headless::resize_window(640, 480);
+            }
+            Ok(State::Keep)
+        }
+
+        fn on_focus_changed(&mut self, _: PlainConnector, focused: bool) -> Result<(), Error> {
+            self.0
+                .borrow_mut()
+                .push(format!("focus window {}", focused));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn it_should_notify_resize_and_focus() {
+        let log = Log::default();
+        let mut manager = test_manager(2);
+        manager
+            .set_init(scripted("a", &log, None))
+            .set_init(scripted("b", &log, None))
+            .set_init(Box::new(Window(log.clone())))
+            .start();
+        assert_eq!(
+            log.take(),
+            vec![
+                "resize a 640x480",
+                "resize b 640x480",
+                "focus a false",
+                "focus b false",
+                "focus window false",
+                "teardown b",
+                "teardown a"
+            ]
+        );
+    }
+
+    thread_local! {
+        static SETUPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Zero-sized, so every boxed instance gets the same address
+    #[derive(Debug)]
+    struct Fresh;
+
+    impl Scene for Fresh {
+        fn on_setup(&mut self, _: PlainConnector) -> Result<(), Error> {
+            SETUPS.with(|setups| setups.set(setups.get() + 1));
+            Ok(())
+        }
+
+        fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+            Ok(State::Replace(Box::new(Fresh)))
+        }
+    }
+
+    #[test]
+    fn it_should_set_every_scene_instance_up_once() {
+        SETUPS.with(|setups| setups.set(0));
+        let mut manager = test_manager(4);
+        manager.set_init(Box::new(Fresh)).start();
+        assert_eq!(SETUPS.with(|setups| setups.get()), 4);
+    }
+
+    #[test]
+    fn it_should_find_scenes_by_name() {
+        let log = Log::default();
+        let mut manager = Rscenes::default();
+        manager
+            .set_init(scripted("a", &log, None))
+            .set_init(scripted("b", &log, None))
+            .set_init(scripted("a", &log, None));
+        assert_eq!(manager.find_scene("a"), Some(SceneHandle(3)));
+        assert_eq!(manager.find_scene("b"), Some(SceneHandle(2)));
+        assert_eq!(manager.find_scene("c"), None);
+        assert_eq!(manager.scenes[1].to_string(), "b #2");
+    }
+
+    #[derive(Debug)]
+    struct Animated {
+        tweens: Tweens,
+        value: Tweened<f32>,
+        seen: Rc<RefCell<Vec<f32>>>,
+    }
+
+    impl Scene for Animated {
+        fn tweens(&mut self) -> Option<&mut Tweens> {
+            Some(&mut self.tweens)
+        }
+
+        fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+            self.seen.borrow_mut().push(self.value.get());
+            Ok(State::Keep)
+        }
+    }
+
+    #[test]
+    fn it_should_advance_scene_tweens() {
+        let value = Tweened::new(0.0);
+        let mut tweens = Tweens::new();
+        tweens.play(Tween::new(&value, 1.0, 1.0));
+        let seen = Rc::new(RefCell::new(vec![]));
+        let mut manager = test_manager(5);
+        Headless::set_frame_time(0.25);
+        manager
+            .set_init(Box::new(Animated {
+                tweens,
+                value,
+                seen: seen.clone(),
+            }))
+            .start();
+        assert_eq!(seen.take(), vec![0.25, 0.5, 0.75, 1.0, 1.0]);
+    }
+
+    #[derive(Debug)]
+    struct Ticking {
+        scheduler: Scheduler,
+        log: Log,
+        updates: usize,
+    }
+
+    impl Scene for Ticking {
+        fn scheduler(&mut self) -> Option<&mut Scheduler> {
+            Some(&mut self.scheduler)
+        }
+
+        fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+            self.updates += 1;
+            Ok(match self.updates {
+                1 => State::Next(scripted("cover", &self.log, Some(State::Prev(1)))),
+                3 => State::Quit,
+                _ => State::Keep,
+            })
+        }
+
+        fn on_teardown(&mut self, _: PlainConnector) -> Result<(), Error> {
+            let pending = !self.scheduler.is_empty();
+            self.log.borrow_mut().push(format!("pending {}", pending));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn it_should_pause_tasks_under_other_scenes() {
+        let manager = test_manager(10);
+        Headless::set_frame_time(0.25);
+        let log = run_scripted(manager, |log| {
+            let mut scheduler = Scheduler::new();
+            let ticks = log.clone();
+            scheduler.every(0.25, move |_| ticks.borrow_mut().push("tick".to_owned()));
+            Box::new(Ticking {
+                scheduler,
+                log: log.clone(),
+                updates: 0,
+            })
+        });
+        assert_eq!(
+            log,
+            vec![
+                "tick",
+                "update cover",
+                "exit cover",
+                "teardown cover",
+                "tick",
+                "tick",
+                "pending false",
+            ]
+        );
+    }
+
+    #[derive(Debug)]
+    struct Optioned(Log);
+
+    impl Scene for Optioned {
+        fn name(&self) -> Option<&str> {
+            Some("options")
+        }
+
+        fn on_setup(&mut self, connector: PlainConnector) -> Result<(), Error> {
+            let options = connector.get_options();
+            self.0.borrow_mut().push(format!(
+                "options {:?} {:?} {}",
+                options.start_scene,
+                options.rest,
+                connector.get_master_volume()
+            ));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn it_should_start_from_the_scene_given_in_the_command_line() {
+        let log = Log::default();
+        let mut manager = test_manager(1);
+        manager.config.audio = true;
+        let options =
+            Options::parse(["--start-scene", "options", "--fullscreen", "--mute", "easy"]).unwrap();
+        manager
+            .set_options(options)
+            .set_init(Box::new(Optioned(log.clone())))
+            .set_init(scripted("menu", &log, None))
+            .start();
+        assert!(manager.config.fullscreen);
+        assert!(PlainConnector.is_window_fullscreen());
+        assert_eq!(log.take(), vec!["options Some(\"options\") [\"easy\"] 0"]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn it_should_still_read_the_deprecated_window_fields() {
+        let log = Log::default();
+        let mut manager = test_manager(1);
+        manager.title = "Balls".to_owned();
+        manager.window_size = (640, 480);
+        let options = Options::parse(["--size", "320x200"]).unwrap();
+        manager
+            .set_options(options)
+            .set_init(scripted("menu", &log, None))
+            .start();
+        assert_eq!(manager.config.title, "Balls");
+        assert_eq!(manager.config.size, (320, 200));
+        assert_eq!(PlainConnector.get_render_width(), 320);
+    }
+
+    #[derive(Debug)]
+    struct Hud;
+
+    impl Scene for Hud {
+        fn draw_hud(&self, connector: Connector2D) -> Result<(), Error> {
+            connector.draw_text("score", 0, 0, 20, Color::BLACK);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn it_should_toggle_the_debug_overlay_over_the_hud() {
+        let mut manager = test_manager(2);
+        Headless::press_key(KeyboardKey::F3);
+        manager.debug_overlay = Some(DebugOverlay::new());
+        manager.set_init(Box::new(Hud)).start();
+        let texts: Vec<String> = Headless::take_draw_calls()
+            .into_iter()
+            .filter(|call| call.name == "draw_text")
+            .map(|call| call.args)
+            .collect();
+        assert!(texts[0].contains("score"));
+        assert!(texts[1].contains("FPS"));
+        assert!(texts.last().unwrap().contains("Hud [0]"));
+        // Held down, the key doesn't hide it again
+        assert!(manager.debug_overlay.unwrap().visible);
+    }
+
+    #[derive(Debug)]
+    struct Board<T> {
+        // Only shown by Debug, which scene names mustn't go through
+        #[allow(dead_code)]
+        cells: Vec<T>,
+    }
+
+    impl<T: fmt::Debug + 'static> Scene for Board<T> {}
+
+    #[test]
+    fn it_should_name_unnamed_scenes_after_their_type() {
+        let stacked = StackedScene {
+            handle: SceneHandle(2),
+            scene: Box::new(Board {
+                cells: vec![Some(1); 100],
+            }),
+            set_up: false,
+        };
+        assert_eq!(stacked.to_string(), "Board<core::option::Option<i32>> #2");
+    }
+
+    #[test]
+    fn it_should_save_a_trace_of_the_scene_callbacks() {
+        let path = std::env::temp_dir().join(format!("rscenes-trace-{}.json", std::process::id()));
+        let mut manager = test_manager(3);
+        manager.profiler = Some(Profiler::new(2).save_on_exit(&path));
+        manager.set_init(Box::new(Hud)).start();
+        let trace = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(trace.starts_with("{\"displayTimeUnit\":\"ms\",\"traceEvents\":["));
+        assert!(trace.contains("\"frame 2\""));
+        assert!(!trace.contains("\"frame 0\""));
+        assert!(trace.contains("\"Hud #1 on_update\""));
+        assert!(trace.contains("\"Hud #1 draw_hud\""));
+        assert!(trace.contains("\"end_drawing\""));
+        assert!(!Profiler::is_enabled());
+    }
+
+    #[derive(Debug)]
+    struct Faulty {
+        fail: &'static str,
+        log: Log,
+        tweens: Tweens,
+    }
+
+    fn faulty(fail: &'static str, log: &Log) -> Box<dyn Scene> {
+        Box::new(Faulty {
+            fail,
+            log: log.clone(),
+            tweens: Tweens::new(),
+        })
+    }
+
+    impl Scene for Faulty {
+        fn name(&self) -> Option<&str> {
+            Some("faulty")
+        }
+
+        fn on_setup(&mut self, _: PlainConnector) -> Result<(), Error> {
+            match self.fail {
+                "setup" => Err("missing level".into()),
+                _ => Ok(()),
+            }
+        }
+
+        fn on_load(&mut self, _: PlainConnector) -> Result<(), Error> {
+            match self.fail {
+                "load" => Err("broken save".into()),
+                _ => Ok(()),
+            }
+        }
+
+        fn tweens(&mut self) -> Option<&mut Tweens> {
+            self.log.borrow_mut().push("animate faulty".to_owned());
+            Some(&mut self.tweens)
+        }
+
+        fn on_update(&mut self, _: PlainConnector, _: f32) -> Result<State, Error> {
+            match self.fail {
+                "update" => panic!("out of cheese"),
+                "error" => Err("out of cheese".into()),
+                _ => Ok(State::Keep),
+            }
+        }
+
+        fn draw_2d(&self, _: Connector2D) -> Result<(), Error> {
+            match self.fail {
+                "draw" => panic!("out of ink"),
+                _ => Ok(()),
+            }
+        }
+
+        fn on_exit(&mut self, _: PlainConnector) -> Result<(), Error> {
+            self.log.borrow_mut().push("exit faulty".to_owned());
+            Ok(())
+        }
+
+        fn on_pause(&mut self, _: PlainConnector) -> Result<(), Error> {
+            self.log.borrow_mut().push("pause faulty".to_owned());
+            Ok(())
+        }
+    }
+
+    fn run_faulty(
+        policy: ErrorPolicy,
+        name: &str,
+        frames: u64,
+        stack: impl FnOnce(&Log) -> Vec<Box<dyn Scene>>,
+    ) -> (Vec<String>, usize) {
+        let log = Log::default();
+        let dir = std::env::temp_dir().join(format!("rscenes-{}-{}", name, std::process::id()));
+        let mut manager = test_manager(frames);
+        manager.config.crash_dir = Some(dir.clone());
+        manager.error_policy = policy;
+        for scene in stack(&log) {
+            manager.set_init(scene);
+        }
+        manager.start();
+        let reports = std::fs::read_dir(&dir).map_or(0, |reports| reports.count());
+        let _ = std::fs::remove_dir_all(&dir);
+        (log.take(), reports)
+    }
+
+    #[test]
+    fn it_should_skip_scenes_panicking() {
+        let (log, reports) = run_faulty(ErrorPolicy::Skip, "skip", 4, |log| {
+            vec![scripted(
+                "root",
+                log,
+                Some(State::Next(faulty("update", log))),
+            )]
+        });
+        assert_eq!(
+            log,
+            [
+                "update root",
+                "exit root",
+                "pause root",
+                "animate faulty",
+                "exit faulty",
+                "resume root None",
+                "update root",
+                "update root",
+                "teardown root"
+            ]
+        );
+        assert_eq!(reports, 1);
+    }
+
+    #[test]
+    fn it_should_skip_frozen_scenes_panicking_under_overlays() {
+        let (log, reports) = run_faulty(ErrorPolicy::Skip, "skip-frozen", 3, |log| {
+            let overlay = Box::new(Layer {
+                name: "overlay",
+                overlay: true,
+                log: log.clone(),
+            });
+            vec![scripted("root", log, None), faulty("draw", log), overlay]
+        });
+        assert_eq!(
+            log,
+            [
+                "update overlay",
+                "draw overlay",
+                "exit faulty",
+                "resume root None",
+                "update root",
+                "teardown root"
+            ]
+        );
+        assert_eq!(reports, 1);
+    }
+
+    #[test]
+    fn it_should_show_the_crash_screen_over_scenes_failing_to_load() {
+        let (log, reports) = run_faulty(ErrorPolicy::CrashScreen, "crash-screen", 4, |log| {
+            vec![scripted(
+                "root",
+                log,
+                Some(State::Next(faulty("load", log))),
+            )]
+        });
+        let texts: Vec<String> = Headless::take_draw_calls()
+            .into_iter()
+            .filter(|call| call.name == "draw_text")
+            .map(|call| call.args)
+            .collect();
+        // Neither animated nor paused, it never got ready
+        assert_eq!(
+            log,
+            ["update root", "exit root", "pause root", "teardown root"]
+        );
+        assert!(texts[0].contains("Something went wrong"));
+        assert!(texts[1].contains("loading faulty #2 scene:"));
+        assert!(texts[2].contains("broken save"));
+        assert!(texts.iter().any(|text| text.contains("[B] back")));
+        assert_eq!(reports, 1);
+    }
+
+    #[test]
+    fn it_should_only_log_update_errors_by_default() {
+        let (log, reports) = run_faulty(ErrorPolicy::default(), "abort", 2, |log| {
+            vec![faulty("error", log)]
+        });
+        assert_eq!(log, ["animate faulty", "animate faulty"]);
+        assert_eq!(reports, 0);
+    }
+}
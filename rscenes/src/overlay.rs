@@ -0,0 +1,240 @@
+use crate::connectors::Connector2D;
+use rscenes_raylib_connector::{assets::*, interface::*};
+use std::{collections::VecDeque, time::Duration};
+
+/// Frames shown in the graph
+const SAMPLES: usize = 120;
+/// Frame time filling the graph height, in seconds
+const GRAPH_SCALE: f32 = 1.0 / 30.0;
+const GRAPH_HEIGHT: i32 = 40;
+const BAR_WIDTH: i32 = 2;
+const MARGIN: i32 = 8;
+const FONT_SIZE: i32 = 10;
+const LINE_HEIGHT: i32 = 12;
+/// Scenes' `Debug` output is cut to this many characters
+const DEBUG_WIDTH: usize = 72;
+/// Weight of the last frame in the phase timings shown, for them to be readable
+const SMOOTHING: f32 = 0.1;
+
+const BACKGROUND: Color = Color {
+    r: 0,
+    g: 0,
+    b: 0,
+    a: 176,
+};
+
+/// Time spent in the phases of a frame, summed over the scenes drawn
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Timings {
+    pub update: Duration,
+    pub draw_2d: Duration,
+    pub draw_3d: Duration,
+    pub draw_hud: Duration,
+}
+
+/// Development overlay drawn by the manager on top of everything, after `Scene::draw_hud()`
+///
+/// Set it on [`Rscenes`](crate::prelude::Rscenes) to show or hide it with `toggle_key`. It
+/// shows a frame time graph, the time spent updating and drawing, the scene stack with each
+/// scene's `Debug` output and loaded assets, and the mouse position in the world of the top
+/// scene's 2D camera.
+#[derive(Debug)]
+pub struct DebugOverlay {
+    /// Key showing and hiding the overlay
+    pub toggle_key: KeyboardKey,
+    /// Whether the overlay is shown
+    pub visible: bool,
+    frame_times: VecDeque<f32>,
+    /// Smoothed phase timings, in milliseconds
+    phases: [f32; 4],
+    stack: Vec<(String, usize)>,
+    mouse: Vector2,
+    world_mouse: Vector2,
+}
+
+impl Default for DebugOverlay {
+    fn default() -> Self {
+        Self {
+            toggle_key: KeyboardKey::F3,
+            visible: false,
+            frame_times: VecDeque::with_capacity(SAMPLES),
+            phases: [0.0; 4],
+            stack: vec![],
+            mouse: Vector2::ZERO,
+            world_mouse: Vector2::ZERO,
+        }
+    }
+}
+
+impl DebugOverlay {
+    /// Hidden overlay toggled by F3
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Account a frame, its timings are kept even while hidden for the graph to be full
+    pub(crate) fn record(&mut self, dt: f32, timings: &Timings) {
+        if self.frame_times.len() == SAMPLES {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(dt);
+        let timings = [
+            timings.update,
+            timings.draw_2d,
+            timings.draw_3d,
+            timings.draw_hud,
+        ];
+        for (phase, timing) in self.phases.iter_mut().zip(timings) {
+            *phase += (timing.as_secs_f32() * 1000.0 - *phase) * SMOOTHING;
+        }
+    }
+
+    /// Take the scene stack, from the bottom up, as labels along with their asset counts
+    pub(crate) fn inspect(&mut self, stack: Vec<(String, usize)>, mouse: Vector2, world: Vector2) {
+        self.stack = stack;
+        self.mouse = mouse;
+        self.world_mouse = world;
+    }
+
+    /// Mean frame time over the graph, in seconds
+    fn mean_frame_time(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            0.0
+        } else {
+            self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32
+        }
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let mean = self.mean_frame_time();
+        let fps = if mean > 0.0 { 1.0 / mean } else { 0.0 };
+        let [update, draw_2d, draw_3d, draw_hud] = self.phases;
+        let assets: usize = self.stack.iter().map(|(_, assets)| assets).sum();
+        let mut lines = vec![
+            format!("{:.0} FPS, {:.2} ms", fps, mean * 1000.0),
+            format!("update  {:.2} ms", update),
+            format!("draw 2D {:.2} ms", draw_2d),
+            format!("draw 3D {:.2} ms", draw_3d),
+            format!("HUD     {:.2} ms", draw_hud),
+            format!(
+                "mouse {:.0}, {:.0} / world {:.1}, {:.1}",
+                self.mouse.x, self.mouse.y, self.world_mouse.x, self.world_mouse.y
+            ),
+            format!("{} assets, {} scenes", assets, self.stack.len()),
+        ];
+        for (label, assets) in self.stack.iter().rev() {
+            let label = match label.char_indices().nth(DEBUG_WIDTH) {
+                Some((end, _)) => format!("{}...", &label[..end]),
+                None => label.clone(),
+            };
+            lines.push(format!("  {} [{}]", label, assets));
+        }
+        lines
+    }
+
+    pub(crate) fn draw(&self, connector: Connector2D) {
+        let lines = self.lines();
+        let graph_width = SAMPLES as i32 * BAR_WIDTH;
+        let width = lines
+            .iter()
+            .map(|line| connector.measure_text(line, FONT_SIZE))
+            .fold(graph_width, i32::max);
+        // The graph goes right below the FPS line
+        let height = GRAPH_HEIGHT + LINE_HEIGHT * lines.len() as i32 + MARGIN;
+        connector.draw_rectangle(0, 0, width + MARGIN * 2, height + MARGIN * 2, BACKGROUND);
+
+        let mut y = MARGIN;
+        let mut lines = lines.iter();
+        if let Some(line) = lines.next() {
+            connector.draw_text(line, MARGIN, y, FONT_SIZE, Color::RAYWHITE);
+            y += LINE_HEIGHT;
+        }
+        self.draw_graph(connector, y);
+        y += GRAPH_HEIGHT + MARGIN;
+        for line in lines {
+            connector.draw_text(line, MARGIN, y, FONT_SIZE, Color::RAYWHITE);
+            y += LINE_HEIGHT;
+        }
+    }
+
+    fn draw_graph(&self, connector: Connector2D, top: i32) {
+        let bottom = top + GRAPH_HEIGHT;
+        for (index, dt) in self.frame_times.iter().enumerate() {
+            let height = ((dt / GRAPH_SCALE).min(1.0) * GRAPH_HEIGHT as f32) as i32;
+            let color = if *dt <= 1.0 / 59.0 {
+                Color::LIME
+            } else if *dt <= 1.0 / 29.0 {
+                Color::ORANGE
+            } else {
+                Color::RED
+            };
+            let x = MARGIN + index as i32 * BAR_WIDTH;
+            connector.draw_rectangle(x, bottom - height, BAR_WIDTH, height, color);
+        }
+        // 60 FPS mark
+        let y = bottom - GRAPH_HEIGHT / 2;
+        let end = MARGIN + SAMPLES as i32 * BAR_WIDTH;
+        connector.draw_line(MARGIN, y, end, y, Color::LIGHTGRAY);
+    }
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(update: u64) -> Timings {
+        Timings {
+            update: Duration::from_millis(update),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_should_keep_the_last_frames() {
+        let mut overlay = DebugOverlay::new();
+        for frame in 0..SAMPLES + 10 {
+            overlay.record(frame as f32, &Timings::default());
+        }
+        assert_eq!(overlay.frame_times.len(), SAMPLES);
+        assert_eq!(overlay.frame_times.front(), Some(&10.0));
+    }
+
+    #[test]
+    fn it_should_smooth_phase_timings() {
+        let mut overlay = DebugOverlay::new();
+        overlay.record(0.02, &timings(10));
+        assert!((overlay.phases[0] - 1.0).abs() < 1e-4);
+        for _ in 0..200 {
+            overlay.record(0.02, &timings(10));
+        }
+        assert!((overlay.phases[0] - 10.0).abs() < 1e-3);
+        assert_eq!(overlay.phases[1], 0.0);
+    }
+
+    #[test]
+    fn it_should_list_the_stack_from_the_top() {
+        let mut overlay = DebugOverlay::new();
+        overlay.record(0.02, &Timings::default());
+        overlay.inspect(
+            vec![
+                ("#1 Menu".to_owned(), 2),
+                ("#2 ".to_owned() + &"x".repeat(100), 3),
+            ],
+            Vector2 { x: 10.0, y: 20.0 },
+            Vector2 { x: 5.0, y: 10.0 },
+        );
+        let lines = overlay.lines();
+        assert_eq!(lines[0], "50 FPS, 20.00 ms");
+        assert_eq!(lines[5], "mouse 10, 20 / world 5.0, 10.0");
+        assert_eq!(lines[6], "5 assets, 2 scenes");
+        assert_eq!(
+            lines[7],
+            format!("  #2 {}... [3]", "x".repeat(DEBUG_WIDTH - 3))
+        );
+        assert_eq!(lines[8], "  #1 Menu [2]");
+    }
+}
@@ -0,0 +1,332 @@
+use crate::error::Error;
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Frame profiler
+///
+/// Set it on [`Rscenes`](crate::prelude::Rscenes) to record the time taken by every scene
+/// callback, frame by frame, along with the scopes opened by the scenes with
+/// `Profiler::scope()`. The last frames are kept and can be saved as Chrome trace events, to
+/// be opened in `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+#[derive(Clone, Debug)]
+pub struct Profiler {
+    /// Frames kept, older ones are dropped
+    pub frames: usize,
+    /// File the trace is saved into when the game stops
+    pub trace_file: Option<PathBuf>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self {
+            frames: 600,
+            trace_file: None,
+        }
+    }
+}
+
+impl Profiler {
+    /// Keep the given number of frames
+    pub fn new(frames: usize) -> Self {
+        Self {
+            frames,
+            ..Default::default()
+        }
+    }
+
+    /// Save the trace into `path` when the game stops
+    pub fn save_on_exit(mut self, path: impl Into<PathBuf>) -> Self {
+        self.trace_file = Some(path.into());
+        self
+    }
+
+    /// Open a named scope, it's closed when the returned guard is dropped
+    ///
+    /// Scopes are only recorded within frames, while the profiler is on.
+    pub fn scope(name: impl Into<Cow<'static, str>>) -> ProfileScope {
+        ProfileScope::open(|| name.into())
+    }
+
+    /// Check whether frames are being recorded
+    pub fn is_enabled() -> bool {
+        RECORDER.with(|recorder| recorder.borrow().is_some())
+    }
+
+    /// Write the recorded frames in the Chrome trace event format
+    pub fn write_trace(writer: impl Write) -> io::Result<()> {
+        RECORDER.with(|recorder| match recorder.borrow().as_ref() {
+            Some(recorder) => recorder.write_trace(writer),
+            None => Recorder::new(0).write_trace(writer),
+        })
+    }
+
+    /// Save the recorded frames into a Chrome trace file
+    pub fn save_trace(path: impl AsRef<Path>) -> Result<(), Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        Self::write_trace(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Start recording
+    pub(crate) fn start(&self) {
+        RECORDER.with(|recorder| *recorder.borrow_mut() = Some(Recorder::new(self.frames)));
+    }
+
+    /// Save the trace when asked to and stop recording
+    pub(crate) fn stop(&self) -> Result<(), Error> {
+        let res = match &self.trace_file {
+            Some(path) => Self::save_trace(path),
+            None => Ok(()),
+        };
+        RECORDER.with(|recorder| recorder.borrow_mut().take());
+        res
+    }
+
+    pub(crate) fn begin_frame() {
+        with_recorder(Recorder::begin_frame);
+    }
+
+    pub(crate) fn end_frame() {
+        with_recorder(Recorder::end_frame);
+    }
+}
+
+/// Open a scope named after a scene callback, the name is only built while profiling
+pub(crate) fn scene_scope(scene: &impl Display, callback: &str) -> ProfileScope {
+    ProfileScope::open(|| format!("{} {}", scene, callback).into())
+}
+
+fn with_recorder(f: impl FnOnce(&mut Recorder)) {
+    RECORDER.with(|recorder| {
+        if let Some(recorder) = recorder.borrow_mut().as_mut() {
+            f(recorder);
+        }
+    });
+}
+
+/// Guard closing a profiling scope when dropped
+#[derive(Debug)]
+#[must_use = "the scope is closed as soon as the guard is dropped"]
+pub struct ProfileScope(Option<(Cow<'static, str>, Instant)>);
+
+impl ProfileScope {
+    fn open(name: impl FnOnce() -> Cow<'static, str>) -> Self {
+        if Profiler::is_enabled() {
+            Self(Some((name(), Instant::now())))
+        } else {
+            Self(None)
+        }
+    }
+}
+
+impl Drop for ProfileScope {
+    fn drop(&mut self) {
+        if let Some((name, start)) = self.0.take() {
+            let end = Instant::now();
+            with_recorder(|recorder| recorder.record(name, start, end));
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Span {
+    name: Cow<'static, str>,
+    /// Since the recorder started
+    start: Duration,
+    duration: Duration,
+}
+
+#[derive(Debug)]
+struct Frame {
+    index: u64,
+    start: Duration,
+    duration: Duration,
+    spans: Vec<Span>,
+}
+
+#[derive(Debug)]
+struct Recorder {
+    epoch: Instant,
+    capacity: usize,
+    frames: VecDeque<Frame>,
+    current: Option<Frame>,
+    next_index: u64,
+}
+
+impl Recorder {
+    fn new(capacity: usize) -> Self {
+        Self {
+            epoch: Instant::now(),
+            capacity,
+            frames: VecDeque::with_capacity(capacity),
+            current: None,
+            next_index: 0,
+        }
+    }
+
+    fn begin_frame(&mut self) {
+        self.end_frame();
+        self.current = Some(Frame {
+            index: self.next_index,
+            start: self.epoch.elapsed(),
+            duration: Duration::ZERO,
+            spans: vec![],
+        });
+        self.next_index += 1;
+    }
+
+    fn end_frame(&mut self) {
+        if let Some(mut frame) = self.current.take() {
+            frame.duration = self.epoch.elapsed().saturating_sub(frame.start);
+            if self.capacity == 0 {
+                return;
+            }
+            if self.frames.len() == self.capacity {
+                self.frames.pop_front();
+            }
+            self.frames.push_back(frame);
+        }
+    }
+
+    fn record(&mut self, name: Cow<'static, str>, start: Instant, end: Instant) {
+        if let Some(frame) = self.current.as_mut() {
+            frame.spans.push(Span {
+                name,
+                start: start.saturating_duration_since(self.epoch),
+                duration: end.saturating_duration_since(start),
+            });
+        }
+    }
+
+    fn write_trace(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "{{\"displayTimeUnit\":\"ms\",\"traceEvents\":[")?;
+        let mut first = true;
+        for frame in self.frames.iter() {
+            let mut spans: Vec<&Span> = frame.spans.iter().collect();
+            // Enclosing scopes first, they close after the scopes they contain
+            spans.sort_by(|a, b| a.start.cmp(&b.start).then(b.duration.cmp(&a.duration)));
+
+            if !first {
+                write!(writer, ",")?;
+            }
+            first = false;
+            let name = format!("frame {}", frame.index);
+            write_event(&mut writer, &name, frame.start, frame.duration)?;
+            for span in spans {
+                write!(writer, ",")?;
+                write_event(&mut writer, &span.name, span.start, span.duration)?;
+            }
+        }
+        writeln!(writer, "]}}")
+    }
+}
+
+fn write_event(
+    mut writer: impl Write,
+    name: &str,
+    start: Duration,
+    duration: Duration,
+) -> io::Result<()> {
+    write!(
+        writer,
+        "{{\"name\":{},\"ph\":\"X\",\"pid\":1,\"tid\":1,\"ts\":{:.3},\"dur\":{:.3}}}",
+        json_string(name),
+        start.as_secs_f64() * 1e6,
+        duration.as_secs_f64() * 1e6,
+    )
+}
+
+fn json_string(value: &str) -> String {
+    let mut res = String::with_capacity(value.len() + 2);
+    res.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            c if c.is_control() => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace() -> String {
+        let mut buffer = vec![];
+        Profiler::write_trace(&mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn it_should_ignore_scopes_when_off() {
+        let scope = Profiler::scope("idle");
+        assert!(scope.0.is_none());
+        assert_eq!(trace(), "{\"displayTimeUnit\":\"ms\",\"traceEvents\":[]}\n");
+    }
+
+    #[test]
+    fn it_should_keep_the_last_frames() {
+        Profiler::new(2).start();
+        for frame in 0..3 {
+            Profiler::begin_frame();
+            let _scope = Profiler::scope(format!("step {frame}"));
+        }
+        Profiler::end_frame();
+        let trace = trace();
+        Profiler::default().stop().unwrap();
+
+        assert!(!trace.contains("step 0"));
+        assert!(trace.contains("{\"name\":\"step 1\",\"ph\":\"X\",\"pid\":1,\"tid\":1,\"ts\":"));
+        assert!(trace.contains("step 2"));
+        assert!(trace.contains("\"frame 1\"") && trace.contains("\"frame 2\""));
+        assert!(!trace.contains("\"frame 0\""));
+        assert!(!Profiler::is_enabled());
+    }
+
+    #[test]
+    fn it_should_list_enclosing_scopes_first() {
+        Profiler::new(1).start();
+        Profiler::begin_frame();
+        {
+            let _outer = Profiler::scope("outer");
+            let _inner = Profiler::scope("inner");
+        }
+        Profiler::end_frame();
+        let trace = trace();
+        Profiler::default().stop().unwrap();
+
+        let position = |name: &str| trace.find(name).unwrap();
+        assert!(position("frame") < position("outer"));
+        assert!(position("outer") < position("inner"));
+    }
+
+    #[test]
+    fn it_should_escape_names() {
+        assert_eq!(
+            json_string("a \"b\"\\\n\t"),
+            "\"a \\\"b\\\"\\\\\\n\\u0009\""
+        );
+    }
+}
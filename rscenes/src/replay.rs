@@ -0,0 +1,268 @@
+use crate::error::Error;
+use rscenes_raylib_connector::{assets::*, interface::*};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Marks the line carrying the seed and frame time, which raylib skips when loading events
+const HEADER: &str = "# rscenes";
+
+/// Input session recording and playback, built on raylib automation events
+///
+/// Set it on [`Rscenes`](crate::prelude::Rscenes) before starting. Both modes pin the frame
+/// time handed to the scenes and the raylib random seed, so a played back session runs exactly
+/// as it was recorded. Games using their own generator should seed it from
+/// `get_random_value()` in a setup callback.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Replay {
+    /// Record input into a text file when the game stops
+    Record {
+        path: PathBuf,
+        /// Seconds per frame, `None` to take it from the target FPS, `Config::fps`
+        frame_time: Option<f32>,
+        seed: u32,
+    },
+    /// Play input back from a recorded file
+    Play(PathBuf),
+}
+
+impl Replay {
+    /// Record at the target FPS with a seed taken from the clock
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as u32)
+            .unwrap_or_default();
+        Self::Record {
+            path: path.into(),
+            frame_time: None,
+            seed,
+        }
+    }
+
+    pub fn play(path: impl Into<PathBuf>) -> Self {
+        Self::Play(path.into())
+    }
+}
+
+/// Running recording or playback
+#[derive(Debug)]
+pub(crate) struct Session {
+    path: PathBuf,
+    mode: Mode,
+    seed: u32,
+    frame_time: f32,
+    frame: u32,
+}
+
+#[derive(Debug)]
+enum Mode {
+    /// raylib keeps a pointer to the list, so it's boxed to stay in place
+    Recording(Box<AutomationEventList>),
+    Playing {
+        events: Vec<AutomationEvent>,
+        next: usize,
+    },
+}
+
+impl Session {
+    /// Pin the seed and start recording or playing, must be called after `init_window()`
+    ///
+    /// Recordings without their own frame time take it from `fps`, the target FPS.
+    pub(crate) fn start(replay: &Replay, fps: i32, connector: impl Rcore) -> Result<Self, Error> {
+        let session = match replay {
+            Replay::Record {
+                path,
+                frame_time,
+                seed,
+            } => {
+                let frame_time = record_frame_time(*frame_time, fps)?;
+                let mut list = Box::new(connector.new_automation_event_list());
+                connector.set_automation_event_list(&mut list);
+                connector.set_automation_event_base_frame(0);
+                connector.start_automation_event_record();
+                Self {
+                    path: path.clone(),
+                    mode: Mode::Recording(list),
+                    seed: *seed,
+                    frame_time,
+                    frame: 0,
+                }
+            }
+            Replay::Play(path) => {
+                let (seed, frame_time) = parse_header(&fs::read(path)?).ok_or_else(|| {
+                    Error::InvalidState(format!("{} is not an rscenes recording", path.display()))
+                })?;
+                Self {
+                    path: path.clone(),
+                    mode: Mode::Playing {
+                        events: connector.load_automation_events(path.display())?,
+                        next: 0,
+                    },
+                    seed,
+                    frame_time,
+                    frame: 0,
+                }
+            }
+        };
+        connector.set_random_seed(session.seed);
+        Ok(session)
+    }
+
+    /// Frame time handed to the scenes in place of the measured one
+    pub(crate) fn frame_time(&self) -> f32 {
+        self.frame_time
+    }
+
+    /// Feed the recorded input for the current frame into raylib
+    pub(crate) fn next_frame(&mut self, connector: impl Rcore) {
+        for event in self.due_events() {
+            connector.play_automation_event(event);
+        }
+    }
+
+    /// Stop recording and save the events, along with the seed and frame time
+    pub(crate) fn finish(self, connector: impl Rcore) -> Result<(), Error> {
+        if let Mode::Recording(list) = self.mode {
+            connector.stop_automation_event_record();
+            let exported = connector.export_automation_event_list(*list, self.path.display());
+            connector.unload_automation_event_list(*list);
+            if !exported {
+                return Err(Error::InvalidState(format!(
+                    "couldn't export input events to {}",
+                    self.path.display()
+                )));
+            }
+            let mut file = OpenOptions::new().append(true).open(&self.path)?;
+            file.write_all(header(self.seed, self.frame_time).as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Events recorded for the current frame, moving on to the next one
+    fn due_events(&mut self) -> Vec<AutomationEvent> {
+        let frame = self.frame;
+        self.frame += 1;
+        match &mut self.mode {
+            Mode::Recording(_) => vec![],
+            Mode::Playing { events, next } => {
+                let start = *next;
+                while *next < events.len() && events[*next].frame <= frame {
+                    *next += 1;
+                }
+                events[start..*next].to_vec()
+            }
+        }
+    }
+}
+
+/// Frame time a session is recorded with, scenes would run slower or faster than the game
+/// otherwise
+fn record_frame_time(frame_time: Option<f32>, fps: i32) -> Result<f32, Error> {
+    match (frame_time, fps) {
+        (Some(frame_time), _) => Ok(frame_time),
+        (None, fps) if fps > 0 => Ok(1.0 / fps as f32),
+        (None, _) => Err(Error::InvalidState(
+            "recording needs a target FPS, the frame rate can't be unlimited".to_owned(),
+        )),
+    }
+}
+
+fn header(seed: u32, frame_time: f32) -> String {
+    // Leading line break, in case the export doesn't end with one
+    format!("\n{} {} {}\n", HEADER, seed, frame_time)
+}
+
+fn parse_header(content: &[u8]) -> Option<(u32, f32)> {
+    let line = content
+        .split(|byte| *byte == b'\n')
+        .rev()
+        .find(|line| line.starts_with(HEADER.as_bytes()))?;
+    let line = std::str::from_utf8(&line[HEADER.len()..]).ok()?;
+    let mut fields = line.split_whitespace();
+    let seed = fields.next()?.parse().ok()?;
+    let frame_time = fields.next()?.parse().ok()?;
+    Some((seed, frame_time))
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(frame: u32) -> AutomationEvent {
+        AutomationEvent {
+            frame,
+            type_: 0,
+            params: [0; 4],
+        }
+    }
+
+    fn playing(frames: &[u32]) -> Session {
+        Session {
+            path: PathBuf::new(),
+            mode: Mode::Playing {
+                events: frames.iter().copied().map(event).collect(),
+                next: 0,
+            },
+            seed: 0,
+            frame_time: 1.0 / 60.0,
+            frame: 0,
+        }
+    }
+
+    #[test]
+    fn it_should_play_events_on_their_frame() {
+        let mut session = playing(&[0, 2, 2, 5]);
+        let frames = (0..7)
+            .map(|_| {
+                session
+                    .due_events()
+                    .iter()
+                    .map(|event| event.frame)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            frames,
+            vec![vec![0], vec![], vec![2, 2], vec![], vec![], vec![5], vec![]]
+        );
+    }
+
+    #[test]
+    fn it_should_record_at_the_target_fps() {
+        assert_eq!(record_frame_time(None, 30).unwrap(), 1.0 / 30.0);
+        assert_eq!(record_frame_time(Some(0.5), 0).unwrap(), 0.5);
+        assert!(matches!(
+            record_frame_time(None, 0),
+            Err(Error::InvalidState(_))
+        ));
+        assert!(record_frame_time(None, -1).is_err());
+    }
+
+    #[test]
+    fn it_should_read_the_header_back() {
+        let mut content = b"# Automation events exporter\nc 1\ne 0 1 2 0 0 0\n".to_vec();
+        content.extend(header(1234, 1.0 / 30.0).bytes());
+        assert_eq!(parse_header(&content), Some((1234, 1.0 / 30.0)));
+    }
+
+    #[test]
+    fn it_should_find_the_header_after_an_unterminated_line() {
+        let mut content = b"c 1\ne 0 1 2 0 0 0".to_vec();
+        content.extend(header(7, 0.5).bytes());
+        assert_eq!(parse_header(&content), Some((7, 0.5)));
+    }
+
+    #[test]
+    fn it_should_reject_files_without_header() {
+        assert_eq!(parse_header(b"c 0\n"), None);
+        assert_eq!(parse_header(b"# rscenes nope\n"), None);
+    }
+}
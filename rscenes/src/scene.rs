@@ -1,14 +1,49 @@
+use crate::cache::AssetCache;
 use crate::connectors::*;
+use crate::error::Error;
+use crate::scheduler::Scheduler;
 use crate::state::State;
+use crate::tween::Tweens;
 use rscenes_raylib_connector::assets::*;
-use std::fmt::Debug;
+use std::any::Any;
+use std::fmt::{self, Debug};
 use std::ptr;
 
+/// Data handed by a popped scene to the scene it gets back to
+pub type Payload = Box<dyn Any>;
+
+/// Unique identifier given by the manager to every scene pushed into the stack
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SceneHandle(pub(crate) u64);
+
+impl fmt::Display for SceneHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
 pub trait Scene: Debug + 'static {
+    #[deprecated(note = "the manager tracks scenes by SceneHandle, addresses can be reused")]
     fn id(&self) -> usize {
         ptr::addr_of!(*self) as *const i32 as usize
     }
 
+    /// Implement name() to give the scene a name for logging and for State::PopTo lookup
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Type of the scene, standing for the name of unnamed scenes
+    #[doc(hidden)]
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Implement is_overlay() to draw the scene over the ones below it, which are kept frozen
+    fn is_overlay(&self) -> bool {
+        false
+    }
+
     /// Implement get_camera_2d() to return your own camera
     fn get_camera_2d(&self) -> Camera2D {
         Camera2D::empty()
@@ -19,45 +54,144 @@ pub trait Scene: Debug + 'static {
         Camera3D::empty()
     }
 
+    /// Implement get_camera_hud() to return the camera #[draw(hud)] uses, the HUD stays fixed
+    /// on screen by default, whatever the 2D camera
+    fn get_camera_hud(&self) -> Camera2D {
+        Camera2D::empty()
+    }
+
     /// Implement on_setup() to run a procedure first time the scene is loaded
     #[allow(unused)]
-    fn on_setup(&mut self, connector: PlainConnector) -> Result<(), String> {
+    fn on_setup(&mut self, connector: PlainConnector) -> Result<(), Error> {
         Ok(())
     }
 
     /// Implement on_load() to run a procedure whenever the scene is reloaded
     #[allow(unused)]
-    fn on_load(&mut self, connector: PlainConnector) -> Result<(), String> {
+    fn on_load(&mut self, connector: PlainConnector) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Implement on_resume() to run a procedure whenever getting back to the scene from the ones
+    /// above it, receiving the payload they left
+    #[allow(unused)]
+    fn on_resume(
+        &mut self,
+        connector: PlainConnector,
+        payload: Option<Payload>,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Implement on_pause() to run a procedure whenever another scene is pushed over this one
+    #[allow(unused)]
+    fn on_pause(&mut self, connector: PlainConnector) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Implement on_resize() to run a procedure whenever the window is resized, it runs for
+    /// every scene in the stack
+    #[allow(unused)]
+    fn on_resize(&mut self, connector: PlainConnector, size: Vector2) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Implement on_focus_changed() to run a procedure whenever the window gains or loses
+    /// focus, it runs for every scene in the stack
+    #[allow(unused)]
+    fn on_focus_changed(&mut self, connector: PlainConnector, focused: bool) -> Result<(), Error> {
         Ok(())
     }
 
+    /// Implement tweens() to have the manager advance the scene animations before each update
+    fn tweens(&mut self) -> Option<&mut Tweens> {
+        None
+    }
+
+    /// Implement scheduler() to have the manager run the scene timers and scripts before each
+    /// update, they're paused while the scene is covered and dropped when it exits
+    fn scheduler(&mut self) -> Option<&mut Scheduler> {
+        None
+    }
+
+    /// Implement assets() to have the manager hot reload the scene assets when enabled
+    fn assets(&mut self) -> Option<&mut AssetCache> {
+        None
+    }
+
+    /// Implement on_assets_reloaded() to re-derive anything depending on reloaded assets, like
+    /// layout from texture sizes
+    #[allow(unused)]
+    fn on_assets_reloaded(&mut self, connector: PlainConnector) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Implement take_payload() to hand data to the scene below when this one is popped
+    fn take_payload(&mut self) -> Option<Payload> {
+        None
+    }
+
     /// Implement on_exit() to run a procedure whenever exiting the schene
     #[allow(unused)]
-    fn on_exit(&mut self, connector: PlainConnector) -> Result<(), String> {
+    fn on_exit(&mut self, connector: PlainConnector) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Implement on_teardown() to release resources when the scene is dropped from the stack
+    #[allow(unused)]
+    fn on_teardown(&mut self, connector: PlainConnector) -> Result<(), Error> {
         Ok(())
     }
 
     /// Implement #[draw(shades)] to render 2D objects
     #[allow(unused)]
-    fn draw_2d(&self, connector: Connector2D) -> Result<(), String> {
+    fn draw_2d(&self, connector: Connector2D) -> Result<(), Error> {
         Ok(())
     }
 
     /// Implement #[draw(models)] to render 3D objects
     #[allow(unused)]
-    fn draw_3d(&self, connector: Connector3D) -> Result<(), String> {
+    fn draw_3d(&self, connector: Connector3D) -> Result<(), Error> {
         Ok(())
     }
 
     /// Implement #[draw(hud)] to render 2D objects on a head-up display
     #[allow(unused)]
-    fn draw_hud(&self, connector: Connector2D) -> Result<(), String> {
+    fn draw_hud(&self, connector: Connector2D) -> Result<(), Error> {
         Ok(())
     }
 
+    /// on_fixed_update() runs with a constant step when fixed timestep is enabled
+    #[allow(unused)]
+    fn on_fixed_update(&mut self, connector: PlainConnector, step: f32) -> Result<State, Error> {
+        Ok(State::Keep)
+    }
+
     /// on_update() runs every loop
     #[allow(unused)]
-    fn on_update(&mut self, connector: PlainConnector, dt: f32) -> Result<State, String> {
+    fn on_update(&mut self, connector: PlainConnector, dt: f32) -> Result<State, Error> {
         Ok(State::Keep)
     }
 }
+
+/// Fields tagged by #[derive(Scene)], the `Scene` methods #[scene] generates go through it
+#[doc(hidden)]
+pub trait SceneFields {
+    fn scene_camera_2d(&self) -> Camera2D {
+        Camera2D::empty()
+    }
+
+    fn scene_camera_3d(&self) -> Camera3D {
+        Camera3D::empty()
+    }
+
+    fn scene_assets(&mut self) -> Option<&mut AssetCache> {
+        None
+    }
+
+    fn scene_load_assets(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn scene_unload_assets(&mut self) {}
+}
@@ -0,0 +1,259 @@
+use crate::connectors::PlainConnector;
+use std::fmt::{self, Debug};
+
+enum Step {
+    Wait(f32),
+    Call(Box<dyn FnMut(PlainConnector)>),
+    Until(Box<dyn FnMut(PlainConnector) -> bool>),
+}
+
+/// Coroutine-style task: steps run one after the other, suspending on waits
+///
+/// Scripts like "wait 1s, play a sound, wait until a key is pressed" are built step by step
+/// with `wait()`, `call()` and `until()`.
+#[derive(Default)]
+pub struct Script {
+    steps: Vec<Step>,
+    forever: bool,
+    current: usize,
+    waited: f32,
+}
+
+impl Script {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Suspend for `secs` seconds
+    pub fn wait(mut self, secs: f32) -> Self {
+        self.steps.push(Step::Wait(secs));
+        self
+    }
+
+    /// Run `callback`
+    pub fn call(mut self, callback: impl FnMut(PlainConnector) + 'static) -> Self {
+        self.steps.push(Step::Call(Box::new(callback)));
+        self
+    }
+
+    /// Suspend until `condition` holds, it's checked once a frame
+    pub fn until(mut self, condition: impl FnMut(PlainConnector) -> bool + 'static) -> Self {
+        self.steps.push(Step::Until(Box::new(condition)));
+        self
+    }
+
+    /// Start over once the last step is done, instead of completing
+    pub fn forever(mut self) -> Self {
+        self.forever = true;
+        self
+    }
+
+    /// Run the steps due within `dt` seconds, return whether the script is complete
+    fn advance(&mut self, connector: PlainConnector, mut dt: f32) -> bool {
+        let mut waited = false;
+        loop {
+            if self.current >= self.steps.len() {
+                if !self.forever {
+                    return true;
+                }
+                self.current = 0;
+                // A pass that didn't wait at all would spin forever within the frame
+                if !waited {
+                    return false;
+                }
+                waited = false;
+            }
+            match &mut self.steps[self.current] {
+                Step::Wait(secs) => {
+                    let left = *secs - self.waited;
+                    if dt < left {
+                        self.waited += dt;
+                        return false;
+                    }
+                    dt -= left.max(0.0);
+                    self.waited = 0.0;
+                    waited |= *secs > 0.0;
+                }
+                Step::Call(callback) => callback(connector),
+                Step::Until(condition) => {
+                    if !condition(connector) {
+                        return false;
+                    }
+                    // The condition was met this frame, later waits start from here
+                    dt = 0.0;
+                }
+            }
+            self.current += 1;
+        }
+    }
+}
+
+impl Debug for Script {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Script")
+            .field("steps", &self.steps.len())
+            .field("forever", &self.forever)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+/// Handle to a task spawned by [`Scheduler`], used to cancel it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaskId(u64);
+
+/// Timers and scripts run by a scene
+///
+/// Return it from `Scene::scheduler()` to have the manager run it every frame, right before
+/// `Scene::on_update()`. Tasks are paused while other scenes cover the scene, and dropped when
+/// it exits.
+#[derive(Default)]
+pub struct Scheduler {
+    next_id: u64,
+    tasks: Vec<(TaskId, Script)>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `callback` once, `secs` seconds from now
+    pub fn after(&mut self, secs: f32, callback: impl FnOnce(PlainConnector) + 'static) -> TaskId {
+        let mut callback = Some(callback);
+        self.spawn(Script::new().wait(secs).call(move |connector| {
+            if let Some(callback) = callback.take() {
+                callback(connector);
+            }
+        }))
+    }
+
+    /// Run `callback` every `secs` seconds, until cancelled
+    pub fn every(&mut self, secs: f32, callback: impl FnMut(PlainConnector) + 'static) -> TaskId {
+        self.spawn(Script::new().wait(secs).call(callback).forever())
+    }
+
+    /// Start running `script`, its first steps run on the next frame
+    pub fn spawn(&mut self, script: Script) -> TaskId {
+        let id = TaskId(self.next_id);
+        self.next_id += 1;
+        self.tasks.push((id, script));
+        id
+    }
+
+    /// Drop a task, its pending steps never run
+    pub fn cancel(&mut self, id: TaskId) {
+        self.tasks.retain(|(task, _)| *task != id);
+    }
+
+    /// Drop every task
+    pub fn clear(&mut self) {
+        self.tasks.clear();
+    }
+
+    /// Check whether a task still has steps to run
+    pub fn is_pending(&self, id: TaskId) -> bool {
+        self.tasks.iter().any(|(task, _)| *task == id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Run what's due within the next `dt` seconds, dropping complete tasks
+    pub fn advance(&mut self, connector: PlainConnector, dt: f32) {
+        self.tasks
+            .retain_mut(|(_, script)| !script.advance(connector, dt));
+    }
+}
+
+impl Debug for Scheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.tasks.iter().map(|(id, script)| (id, script)))
+            .finish()
+    }
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    type Log = Rc<RefCell<Vec<&'static str>>>;
+
+    fn logger(log: &Log, entry: &'static str) -> impl FnMut(PlainConnector) + 'static {
+        let log = log.clone();
+        move |_| log.borrow_mut().push(entry)
+    }
+
+    #[test]
+    fn it_should_run_once_after_the_delay() {
+        let log = Log::default();
+        let mut scheduler = Scheduler::new();
+        let id = scheduler.after(1.0, logger(&log, "once"));
+        scheduler.advance(PlainConnector, 0.5);
+        assert!(log.borrow().is_empty());
+        scheduler.advance(PlainConnector, 0.5);
+        assert_eq!(*log.borrow(), vec!["once"]);
+        assert!(!scheduler.is_pending(id));
+        scheduler.advance(PlainConnector, 5.0);
+        assert_eq!(*log.borrow(), vec!["once"]);
+    }
+
+    #[test]
+    fn it_should_run_periodically_until_cancelled() {
+        let log = Log::default();
+        let mut scheduler = Scheduler::new();
+        let id = scheduler.every(0.25, logger(&log, "tick"));
+        scheduler.advance(PlainConnector, 0.2);
+        assert!(log.borrow().is_empty());
+        scheduler.advance(PlainConnector, 0.6);
+        assert_eq!(log.borrow().len(), 3);
+        scheduler.cancel(id);
+        scheduler.advance(PlainConnector, 1.0);
+        assert_eq!(log.borrow().len(), 3);
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    fn it_should_suspend_scripts() {
+        let log = Log::default();
+        let ready = Rc::new(RefCell::new(false));
+        let mut scheduler = Scheduler::new();
+        let condition = ready.clone();
+        scheduler.spawn(
+            Script::new()
+                .wait(1.0)
+                .call(logger(&log, "waited"))
+                .until(move |_| *condition.borrow())
+                .call(logger(&log, "ready"))
+                .wait(0.5)
+                .call(logger(&log, "done")),
+        );
+        scheduler.advance(PlainConnector, 1.0);
+        assert_eq!(*log.borrow(), vec!["waited"]);
+        scheduler.advance(PlainConnector, 1.0);
+        assert_eq!(*log.borrow(), vec!["waited"]);
+        *ready.borrow_mut() = true;
+        scheduler.advance(PlainConnector, 1.0);
+        assert_eq!(*log.borrow(), vec!["waited", "ready"]);
+        scheduler.advance(PlainConnector, 0.5);
+        assert_eq!(*log.borrow(), vec!["waited", "ready", "done"]);
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    fn it_should_not_spin_scripts_without_waits() {
+        let log = Log::default();
+        let mut scheduler = Scheduler::new();
+        scheduler.spawn(Script::new().call(logger(&log, "frame")).forever());
+        scheduler.advance(PlainConnector, 1.0);
+        scheduler.advance(PlainConnector, 1.0);
+        assert_eq!(log.borrow().len(), 2);
+    }
+}
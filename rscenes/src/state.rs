@@ -1,4 +1,5 @@
-use crate::scene::Scene;
+use crate::{scene::Scene, transition::Transition};
+use std::fmt;
 
 #[derive(Debug)]
 pub enum State {
@@ -6,8 +7,64 @@ pub enum State {
     Keep,
     /// Add a new scene and run it
     Next(Box<dyn Scene>),
+    /// Add a new scene and run it, animating the switch
+    NextWith(Box<dyn Scene>, Transition),
     /// Get back to the previous scene (or quit if there's no one)
     Prev(usize),
+    /// Get back to the previous scene, animating the switch
+    PrevWith(usize, Transition),
+    /// Drop the current scene and run a new one in its place
+    Replace(Box<dyn Scene>),
+    /// Get back to the topmost scene matching, dropping all scenes above it
+    PopTo(SceneMatcher),
+    /// Get back to the first scene, dropping all the others
+    PopToRoot,
+    /// Drop all scenes and start over from a new one
+    Reset(Box<dyn Scene>),
     /// Quit
     Quit,
 }
+
+/// Select a scene in the stack
+pub enum SceneMatcher {
+    /// Match scenes by their name
+    Name(String),
+    /// Match scenes by a custom predicate
+    Predicate(Box<dyn ScenePredicate>),
+}
+
+impl SceneMatcher {
+    /// Match scenes which name is `name`
+    pub fn name(name: impl Into<String>) -> Self {
+        Self::Name(name.into())
+    }
+
+    /// Match scenes for which `predicate` returns true
+    pub fn predicate(predicate: impl ScenePredicate) -> Self {
+        Self::Predicate(Box::new(predicate))
+    }
+
+    pub fn matches(&self, scene: &dyn Scene) -> bool {
+        match self {
+            Self::Name(name) => scene.name() == Some(name.as_str()),
+            Self::Predicate(predicate) => predicate(scene),
+        }
+    }
+}
+
+impl fmt::Debug for SceneMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name(name) => f.debug_tuple("Name").field(name).finish(),
+            Self::Predicate(_) => f.write_str("Predicate(..)"),
+        }
+    }
+}
+
+impl From<&str> for SceneMatcher {
+    fn from(value: &str) -> Self {
+        Self::name(value)
+    }
+}
+
+pub trait ScenePredicate = Fn(&dyn Scene) -> bool + 'static;
@@ -0,0 +1,107 @@
+use std::cell::Cell;
+
+thread_local! {
+    static ALPHA: Cell<f32> = const { Cell::new(1.0) };
+}
+
+/// Fixed-timestep simulation settings
+///
+/// When set on [`Rscenes`](crate::prelude::Rscenes), `Scene::on_fixed_update()` runs zero or
+/// more times per frame with a constant `step`, before the variable-step `Scene::on_update()`.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedTimestep {
+    /// Simulation step in seconds
+    pub step: f32,
+    /// Maximum steps run in a single frame, the remaining time is dropped
+    pub max_steps: usize,
+}
+
+impl FixedTimestep {
+    /// Create settings for the given step, in seconds
+    pub fn new(step: f32) -> Self {
+        Self {
+            step,
+            ..Default::default()
+        }
+    }
+
+    /// Feed the frame time into the accumulator and return how many steps should run
+    pub(crate) fn advance(&self, accumulator: &mut f32, dt: f32) -> usize {
+        if self.step <= 0.0 {
+            return 0;
+        }
+        *accumulator += dt;
+        let steps = ((*accumulator / self.step) as usize).min(self.max_steps);
+        *accumulator -= steps as f32 * self.step;
+        if *accumulator >= self.step {
+            // Too far behind: drop the time that cannot be caught up
+            *accumulator %= self.step;
+        }
+        steps
+    }
+
+    /// How far the simulation is between the last step and the next one
+    pub(crate) fn alpha(&self, accumulator: f32) -> f32 {
+        if self.step <= 0.0 {
+            1.0
+        } else {
+            (accumulator / self.step).clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self {
+            step: 1.0 / 60.0,
+            max_steps: 5,
+        }
+    }
+}
+
+pub(crate) fn set_interpolation_alpha(alpha: f32) {
+    ALPHA.with(|cell| cell.set(alpha));
+}
+
+pub(crate) fn get_interpolation_alpha() -> f32 {
+    ALPHA.with(|cell| cell.get())
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_run_whole_steps_only() {
+        let timestep = FixedTimestep::new(0.1);
+        let mut accumulator = 0.0;
+        assert_eq!(timestep.advance(&mut accumulator, 0.05), 0);
+        assert_eq!(timestep.advance(&mut accumulator, 0.2), 2);
+        assert!((accumulator - 0.05).abs() < 1e-5);
+        assert!((timestep.alpha(accumulator) - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn it_should_cap_catch_up_steps() {
+        let timestep = FixedTimestep {
+            step: 0.1,
+            max_steps: 3,
+        };
+        let mut accumulator = 0.0;
+        assert_eq!(timestep.advance(&mut accumulator, 1.05), 3);
+        assert!(accumulator < timestep.step);
+        assert!((accumulator - 0.05).abs() < 1e-5);
+    }
+
+    #[test]
+    fn it_should_ignore_non_positive_step() {
+        let timestep = FixedTimestep::new(0.0);
+        let mut accumulator = 0.0;
+        assert_eq!(timestep.advance(&mut accumulator, 1.0), 0);
+        assert_eq!(timestep.alpha(accumulator), 1.0);
+    }
+}
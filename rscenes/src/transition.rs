@@ -0,0 +1,342 @@
+use crate::connectors::*;
+use rscenes_raylib_connector::{assets::*, interface::*};
+
+/// Animation played while switching scenes, durations are in seconds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transition {
+    /// Cross-fade from the outgoing scene to the incoming one
+    Fade(f32),
+    /// Push the outgoing scene out of the screen with the incoming one
+    Slide(Direction, f32),
+    /// Uncover the incoming scene over the outgoing one
+    Wipe(Direction, f32),
+}
+
+/// Direction the transitions move towards
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Transition {
+    /// How long the transition lasts
+    pub fn duration(&self) -> f32 {
+        match self {
+            Self::Fade(duration) => *duration,
+            Self::Slide(_, duration) => *duration,
+            Self::Wipe(_, duration) => *duration,
+        }
+    }
+}
+
+impl Direction {
+    fn offset(&self, width: f32, height: f32) -> Vector2 {
+        match self {
+            Self::Left => Vector2 { x: -width, y: 0.0 },
+            Self::Right => Vector2 { x: width, y: 0.0 },
+            Self::Up => Vector2 { x: 0.0, y: -height },
+            Self::Down => Vector2 { x: 0.0, y: height },
+        }
+    }
+}
+
+/// Transition being played: both scenes are rendered into textures and then blended
+#[derive(Debug)]
+pub(crate) struct ActiveTransition {
+    transition: Transition,
+    elapsed: f32,
+    from: RenderTexture,
+    to: RenderTexture,
+}
+
+impl ActiveTransition {
+    pub fn new(transition: Transition) -> Self {
+        let connector = PlainConnector;
+        let width = connector.get_render_width();
+        let height = connector.get_render_height();
+        Self {
+            transition,
+            elapsed: 0.0,
+            from: connector.load_render_texture(width, height),
+            to: connector.load_render_texture(width, height),
+        }
+    }
+
+    /// Render the outgoing scene
+    pub fn capture(&self, draw: impl FnOnce()) {
+        render_into(self.from, draw);
+    }
+
+    /// Render the incoming scene and blend it with the outgoing one, return whether the
+    /// transition is over
+    pub fn render(&mut self, dt: f32, draw: impl FnOnce()) -> bool {
+        self.fit_incoming();
+        render_into(self.to, draw);
+        self.compose(Connector2D);
+        self.elapsed += dt;
+        self.elapsed >= self.transition.duration()
+    }
+
+    pub fn unload(self) {
+        let connector = PlainConnector;
+        connector.unload_render_texture(self.from);
+        connector.unload_render_texture(self.to);
+    }
+
+    /// Recreate the incoming scene texture when the window got resized, the outgoing scene
+    /// capture gets scaled instead
+    fn fit_incoming(&mut self) {
+        let connector = PlainConnector;
+        let width = connector.get_render_width();
+        let height = connector.get_render_height();
+        if (self.to.texture.width, self.to.texture.height) != (width, height) {
+            connector.unload_render_texture(self.to);
+            self.to = connector.load_render_texture(width, height);
+        }
+    }
+
+    fn progress(&self) -> f32 {
+        let duration = self.transition.duration();
+        if duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / duration).clamp(0.0, 1.0)
+        }
+    }
+
+    fn compose(&self, connector: Connector2D) {
+        let t = self.progress();
+        let width = connector.get_render_width() as f32;
+        let height = connector.get_render_height() as f32;
+        let screen = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width,
+            height,
+        };
+        connector.clear_background(Color::BLANK);
+
+        match self.transition {
+            Transition::Fade(_) => {
+                draw_region(connector, self.from, screen, screen, Color::WHITE);
+                draw_region(connector, self.to, screen, screen, Color::WHITE.fade(t));
+            }
+
+            Transition::Slide(direction, _) => {
+                let offset = direction.offset(width, height);
+                let from = Rectangle {
+                    x: offset.x * t,
+                    y: offset.y * t,
+                    ..screen
+                };
+                let to = Rectangle {
+                    x: offset.x * (t - 1.0),
+                    y: offset.y * (t - 1.0),
+                    ..screen
+                };
+                draw_texture_at(connector, self.from, from);
+                draw_texture_at(connector, self.to, to);
+            }
+
+            Transition::Wipe(direction, _) => {
+                let region = match direction {
+                    Direction::Right => Rectangle {
+                        width: width * t,
+                        ..screen
+                    },
+                    Direction::Left => Rectangle {
+                        x: width * (1.0 - t),
+                        width: width * t,
+                        ..screen
+                    },
+                    Direction::Down => Rectangle {
+                        height: height * t,
+                        ..screen
+                    },
+                    Direction::Up => Rectangle {
+                        y: height * (1.0 - t),
+                        height: height * t,
+                        ..screen
+                    },
+                };
+                draw_region(connector, self.from, screen, screen, Color::WHITE);
+                draw_region(connector, self.to, screen, region, Color::WHITE);
+            }
+        }
+    }
+}
+
+fn render_into(target: RenderTexture, draw: impl FnOnce()) {
+    let connector = PlainConnector;
+    connector.begin_texture_mode(target);
+    connector.clear_background(Color::BLANK);
+    draw();
+    connector.end_texture_mode();
+}
+
+/// Draw the whole texture on the given screen area
+fn draw_texture_at(connector: Connector2D, target: RenderTexture, dest: Rectangle) {
+    let source = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: target.texture.width as f32,
+        // Render textures are upside down
+        height: -target.texture.height as f32,
+    };
+    connector.draw_texture_pro(
+        target.texture,
+        source,
+        dest,
+        Vector2::ZERO,
+        0.0,
+        Color::WHITE,
+    );
+}
+
+/// Draw only the given screen area of the texture, scaled to the screen when it was rendered
+/// at another size
+fn draw_region(
+    connector: Connector2D,
+    target: RenderTexture,
+    screen: Rectangle,
+    region: Rectangle,
+    tint: Color,
+) {
+    let scale_x = target.texture.width as f32 / screen.width;
+    let scale_y = target.texture.height as f32 / screen.height;
+    let source = Rectangle {
+        x: region.x * scale_x,
+        y: target.texture.height as f32 - (region.y + region.height) * scale_y,
+        width: region.width * scale_x,
+        // Render textures are upside down
+        height: -region.height * scale_y,
+    };
+    connector.draw_texture_pro(target.texture, source, region, Vector2::ZERO, 0.0, tint);
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(all(test, feature = "headless"))]
+mod tests {
+    use super::*;
+    use crate::extras::Headless;
+
+    fn draw_dests() -> Vec<String> {
+        Headless::take_draw_calls()
+            .into_iter()
+            .filter(|call| call.name == "draw_texture_pro")
+            .map(|call| call.args)
+            .collect()
+    }
+
+    #[test]
+    fn it_should_finish_after_duration() {
+        Headless::reset();
+        let mut active = ActiveTransition::new(Transition::Fade(0.1));
+        assert!(!active.render(0.05, || ()));
+        assert!(active.render(0.05, || ()));
+        active.unload();
+        let unloads = Headless::take_draw_calls()
+            .into_iter()
+            .filter(|call| call.name == "unload_render_texture")
+            .count();
+        assert_eq!(unloads, 2);
+    }
+
+    #[test]
+    fn it_should_render_both_scenes_into_textures() {
+        Headless::reset();
+        let mut active = ActiveTransition::new(Transition::Fade(1.0));
+        active.capture(|| Connector2D.draw_pixel(1, 1, Color::RED));
+        active.render(0.5, || Connector2D.draw_pixel(2, 2, Color::BLUE));
+        let names: Vec<&str> = Headless::take_draw_calls()
+            .iter()
+            .map(|call| call.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "load_render_texture",
+                "load_render_texture",
+                "begin_texture_mode",
+                "clear_background",
+                "draw_pixel",
+                "end_texture_mode",
+                "begin_texture_mode",
+                "clear_background",
+                "draw_pixel",
+                "end_texture_mode",
+                "clear_background",
+                "draw_texture_pro",
+                "draw_texture_pro",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_slide_scenes() {
+        Headless::reset();
+        Headless::set_render_size(100, 50);
+        let mut active = ActiveTransition::new(Transition::Slide(Direction::Left, 1.0));
+        active.elapsed = 0.25;
+        Headless::take_draw_calls();
+        active.compose(Connector2D);
+        let dests = draw_dests();
+        assert_eq!(dests.len(), 2);
+        assert!(dests[0].contains("x: -25.0"), "{}", dests[0]);
+        assert!(dests[1].contains("x: 75.0"), "{}", dests[1]);
+    }
+
+    #[test]
+    fn it_should_wipe_scenes() {
+        Headless::reset();
+        Headless::set_render_size(100, 50);
+        let mut active = ActiveTransition::new(Transition::Wipe(Direction::Up, 1.0));
+        active.elapsed = 0.5;
+        Headless::take_draw_calls();
+        active.compose(Connector2D);
+        let dests = draw_dests();
+        assert_eq!(dests.len(), 2);
+        assert!(
+            dests[1].contains("y: 25.0, width: 100.0, height: 25.0"),
+            "{}",
+            dests[1]
+        );
+    }
+
+    #[test]
+    fn it_should_fit_the_window_resized_during_transitions() {
+        Headless::reset();
+        Headless::set_render_size(100, 50);
+        let mut active = ActiveTransition::new(Transition::Fade(1.0));
+        active.capture(|| ());
+        Headless::set_render_size(200, 100);
+        Headless::take_draw_calls();
+        active.render(0.5, || ());
+        let calls = Headless::take_draw_calls();
+        assert_eq!(calls[0].name, "unload_render_texture");
+        assert_eq!(calls[1].name, "load_render_texture");
+        assert_eq!(calls[1].args, "(200, 100)");
+        let blits: Vec<&String> = calls
+            .iter()
+            .filter(|call| call.name == "draw_texture_pro")
+            .map(|call| &call.args)
+            .collect();
+        // The outgoing capture is stretched from its 100x50 source
+        assert!(
+            blits[0].contains("width: 100.0, height: -50.0 }, Rectangle { x: 0.0, y: 0.0, width: 200.0, height: 100.0"),
+            "{}",
+            blits[0]
+        );
+        assert!(
+            blits[1].contains("width: 200.0, height: -100.0"),
+            "{}",
+            blits[1]
+        );
+    }
+}
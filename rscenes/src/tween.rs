@@ -0,0 +1,851 @@
+use rscenes_raylib_connector::assets::*;
+use std::{
+    cell::Cell,
+    f32::consts::PI,
+    fmt::{self, Debug},
+    rc::Rc,
+};
+
+/// Easing curves, mapping linear progress in `0..=1` onto animated progress
+///
+/// Back and elastic curves overshoot, going slightly out of `0..=1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Ease {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Ease {
+    /// Eased progress for `t`, which is clamped into `0..=1`
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadIn => t.powi(2),
+            Self::QuadOut => ease_out(t, |t| t.powi(2)),
+            Self::QuadInOut => ease_in_out(t, |t| t.powi(2)),
+            Self::CubicIn => t.powi(3),
+            Self::CubicOut => ease_out(t, |t| t.powi(3)),
+            Self::CubicInOut => ease_in_out(t, |t| t.powi(3)),
+            Self::QuartIn => t.powi(4),
+            Self::QuartOut => ease_out(t, |t| t.powi(4)),
+            Self::QuartInOut => ease_in_out(t, |t| t.powi(4)),
+            Self::QuintIn => t.powi(5),
+            Self::QuintOut => ease_out(t, |t| t.powi(5)),
+            Self::QuintInOut => ease_in_out(t, |t| t.powi(5)),
+            Self::SineIn => sine_in(t),
+            Self::SineOut => ease_out(t, sine_in),
+            Self::SineInOut => ease_in_out(t, sine_in),
+            Self::ExpoIn => expo_in(t),
+            Self::ExpoOut => ease_out(t, expo_in),
+            Self::ExpoInOut => ease_in_out(t, expo_in),
+            Self::CircIn => circ_in(t),
+            Self::CircOut => ease_out(t, circ_in),
+            Self::CircInOut => ease_in_out(t, circ_in),
+            Self::BackIn => back_in(t),
+            Self::BackOut => ease_out(t, back_in),
+            Self::BackInOut => ease_in_out(t, back_in),
+            Self::ElasticIn => elastic_in(t),
+            Self::ElasticOut => ease_out(t, elastic_in),
+            Self::ElasticInOut => ease_in_out(t, elastic_in),
+            Self::BounceIn => ease_out(t, bounce_out),
+            Self::BounceOut => bounce_out(t),
+            Self::BounceInOut => ease_in_out(t, |t| ease_out(t, bounce_out)),
+        }
+    }
+}
+
+/// Mirror an ease-in curve into its ease-out counterpart
+fn ease_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    1.0 - ease_in(1.0 - t)
+}
+
+/// Ease in over the first half, out over the second one
+fn ease_in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) / 2.0
+    } else {
+        1.0 - ease_in(2.0 - t * 2.0) / 2.0
+    }
+}
+
+fn sine_in(t: f32) -> f32 {
+    1.0 - (t * PI / 2.0).cos()
+}
+
+fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2.0_f32.powf(10.0 * t - 10.0)
+    }
+}
+
+fn circ_in(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).sqrt()
+}
+
+fn back_in(t: f32) -> f32 {
+    const OVERSHOOT: f32 = 1.70158;
+    (OVERSHOOT + 1.0) * t.powi(3) - OVERSHOOT * t.powi(2)
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        t
+    } else {
+        -(2.0_f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * PI * 2.0 / 3.0).sin()
+    }
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// Values that can be interpolated by tweens
+pub trait Lerp: Copy + 'static {
+    /// Value `t` of the way from `self` to `to`, `t` may go out of `0..=1` when overshooting
+    fn lerp(self, to: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Lerp for Vector2 {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        Self {
+            x: self.x.lerp(to.x, t),
+            y: self.y.lerp(to.y, t),
+        }
+    }
+}
+
+impl Lerp for Vector3 {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        Self {
+            x: self.x.lerp(to.x, t),
+            y: self.y.lerp(to.y, t),
+            z: self.z.lerp(to.z, t),
+        }
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        let channel =
+            |from: u8, to: u8| (from as f32).lerp(to as f32, t).round().clamp(0.0, 255.0) as u8;
+        Self {
+            r: channel(self.r, to.r),
+            g: channel(self.g, to.g),
+            b: channel(self.b, to.b),
+            a: channel(self.a, to.a),
+        }
+    }
+}
+
+impl Lerp for Rectangle {
+    fn lerp(self, to: Self, t: f32) -> Self {
+        Self {
+            x: self.x.lerp(to.x, t),
+            y: self.y.lerp(to.y, t),
+            width: self.width.lerp(to.width, t),
+            height: self.height.lerp(to.height, t),
+        }
+    }
+}
+
+/// Value driven by tweens, shared between the scene and the animations writing into it
+#[derive(Clone, Debug, Default)]
+pub struct Tweened<T: Copy>(Rc<Cell<T>>);
+
+impl<T: Copy> Tweened<T> {
+    pub fn new(value: T) -> Self {
+        Self(Rc::new(Cell::new(value)))
+    }
+
+    /// Current value
+    pub fn get(&self) -> T {
+        self.0.get()
+    }
+
+    /// Overwrite the value, running tweens keep writing into it
+    pub fn set(&self, value: T) {
+        self.0.set(value)
+    }
+}
+
+/// Anything playable by [`Tweens`]
+///
+/// Animations are driven by seeking: they get the time elapsed since they started and bring
+/// their values to that point, either forward or backward.
+pub trait Animation: 'static {
+    /// Length in seconds, infinite for endless repeats
+    fn duration(&self) -> f32;
+
+    /// Bring the animated values to where they are `time` seconds after starting
+    fn seek(&mut self, time: f32);
+
+    /// Call `callback` once the animation completes
+    fn then(self, callback: impl FnMut() + 'static) -> Sequence
+    where
+        Self: Sized,
+    {
+        Sequence::new().with(self).call(callback)
+    }
+
+    /// Play the animation `times` times in a row
+    fn repeat(self, times: u32) -> Repeat
+    where
+        Self: Sized,
+    {
+        Repeat::new(self, Some(times), false)
+    }
+
+    /// Play the animation over and over, it never completes
+    fn forever(self) -> Repeat
+    where
+        Self: Sized,
+    {
+        Repeat::new(self, None, false)
+    }
+
+    /// Play the animation and then backwards, chain with `repeat()` or `forever()` to go on
+    fn yoyo(self) -> Repeat
+    where
+        Self: Sized,
+    {
+        Repeat::new(self, Some(2), true)
+    }
+}
+
+/// Interpolate a [`Tweened`] value towards a target
+#[derive(Debug)]
+pub struct Tween<T: Lerp> {
+    target: Tweened<T>,
+    from: Option<T>,
+    to: T,
+    duration: f32,
+    ease: Ease,
+}
+
+impl<T: Lerp> Tween<T> {
+    /// Move `target` to `to` in `duration` seconds, starting from its value when the tween starts
+    pub fn new(target: &Tweened<T>, to: T, duration: f32) -> Self {
+        Self {
+            target: target.clone(),
+            from: None,
+            to,
+            duration,
+            ease: Ease::default(),
+        }
+    }
+
+    /// Start from `from` instead of the current value
+    pub fn from(mut self, from: T) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Set the easing curve, linear by default
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+}
+
+impl<T: Lerp> Animation for Tween<T> {
+    fn duration(&self) -> f32 {
+        self.duration.max(0.0)
+    }
+
+    fn seek(&mut self, time: f32) {
+        let target = &self.target;
+        let from = *self.from.get_or_insert_with(|| target.get());
+        let t = if self.duration <= 0.0 {
+            1.0
+        } else {
+            time / self.duration
+        };
+        target.set(from.lerp(self.to, self.ease.apply(t)));
+    }
+}
+
+/// Do nothing for a while, useful within sequences
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Delay(pub f32);
+
+impl Animation for Delay {
+    fn duration(&self) -> f32 {
+        self.0.max(0.0)
+    }
+
+    fn seek(&mut self, _: f32) {}
+}
+
+enum Step {
+    Play(Box<dyn Animation>),
+    /// Callbacks only run when the sequence moves forward past them
+    Call(Box<dyn FnMut()>),
+}
+
+impl Step {
+    fn duration(&self) -> f32 {
+        match self {
+            Self::Play(animation) => animation.duration(),
+            Self::Call(_) => 0.0,
+        }
+    }
+}
+
+/// Animations played one after the other, with callbacks in between
+#[derive(Default)]
+pub struct Sequence {
+    steps: Vec<Step>,
+    position: Option<f32>,
+}
+
+impl Sequence {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Play `animation` after the previous steps
+    pub fn with(mut self, animation: impl Animation) -> Self {
+        self.steps.push(Step::Play(Box::new(animation)));
+        self
+    }
+
+    /// Wait for `duration` seconds
+    pub fn delay(self, duration: f32) -> Self {
+        self.with(Delay(duration))
+    }
+
+    /// Call `callback` once the previous steps are done
+    pub fn call(mut self, callback: impl FnMut() + 'static) -> Self {
+        self.steps.push(Step::Call(Box::new(callback)));
+        self
+    }
+}
+
+impl Animation for Sequence {
+    fn duration(&self) -> f32 {
+        self.steps.iter().map(Step::duration).sum()
+    }
+
+    fn seek(&mut self, time: f32) {
+        let time = time.clamp(0.0, self.duration());
+        let previous = self.position.replace(time);
+        let forward = match previous {
+            Some(previous) if previous == time => return,
+            Some(previous) => previous < time,
+            None => true,
+        };
+        let previous = previous.unwrap_or(f32::NEG_INFINITY);
+
+        let mut start = 0.0;
+        let mut spans = Vec::with_capacity(self.steps.len());
+        for step in self.steps.iter() {
+            let end = start + step.duration();
+            spans.push((start, end));
+            start = end;
+        }
+        let steps = self.steps.iter_mut().zip(spans);
+
+        if forward {
+            // Steps overlapping (previous, time], in order
+            for (step, (start, end)) in steps {
+                if start > time {
+                    break;
+                }
+                if end > previous {
+                    match step {
+                        Step::Play(animation) => animation.seek(time.min(end) - start),
+                        Step::Call(callback) => callback(),
+                    }
+                }
+            }
+        } else {
+            // Steps overlapping [time, previous), in reverse order
+            for (step, (start, end)) in steps.rev() {
+                if end < time {
+                    break;
+                }
+                if start < previous {
+                    if let Step::Play(animation) = step {
+                        animation.seek(time.max(start) - start);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Debug for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sequence")
+            .field("steps", &self.steps.len())
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+/// Animations played at the same time, completing along with the longest one
+#[derive(Default)]
+pub struct Parallel(Vec<Box<dyn Animation>>);
+
+impl Parallel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Play `animation` along with the others
+    pub fn with(mut self, animation: impl Animation) -> Self {
+        self.0.push(Box::new(animation));
+        self
+    }
+}
+
+impl Animation for Parallel {
+    fn duration(&self) -> f32 {
+        self.0
+            .iter()
+            .map(|animation| animation.duration())
+            .fold(0.0, f32::max)
+    }
+
+    fn seek(&mut self, time: f32) {
+        for animation in self.0.iter_mut() {
+            animation.seek(time.clamp(0.0, animation.duration()));
+        }
+    }
+}
+
+impl Debug for Parallel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Parallel").field(&self.0.len()).finish()
+    }
+}
+
+/// Animation played several times, going back and forth when yoyo is set
+pub struct Repeat {
+    animation: Box<dyn Animation>,
+    times: Option<u32>,
+    yoyo: bool,
+    cycle: Option<u32>,
+}
+
+impl Repeat {
+    /// Repeat `animation` the given number of times, or forever when `None`
+    pub fn new(animation: impl Animation, times: Option<u32>, yoyo: bool) -> Self {
+        Self {
+            animation: Box::new(animation),
+            times,
+            yoyo,
+            cycle: None,
+        }
+    }
+
+    /// Time within the repeated animation for `local` seconds into `cycle`
+    fn local_time(&self, cycle: u32, local: f32) -> f32 {
+        if self.yoyo && cycle % 2 == 1 {
+            self.animation.duration() - local
+        } else {
+            local
+        }
+    }
+}
+
+impl Animation for Repeat {
+    fn duration(&self) -> f32 {
+        match self.times {
+            Some(times) => self.animation.duration() * times as f32,
+            None => f32::INFINITY,
+        }
+    }
+
+    fn seek(&mut self, time: f32) {
+        let length = self.animation.duration();
+        if length <= 0.0 || self.times == Some(0) {
+            self.animation.seek(0.0);
+            return;
+        }
+        let time = time.clamp(0.0, self.duration());
+        let (cycle, local) = match self.times {
+            Some(times) if time >= self.duration() => (times - 1, length),
+            _ => {
+                let cycle = (time / length).floor();
+                (cycle as u32, time - cycle * length)
+            }
+        };
+
+        match self.cycle.replace(cycle) {
+            // Finish the previous cycle before jumping into the current one
+            Some(previous) if previous < cycle => {
+                self.animation.seek(self.local_time(previous, length));
+                self.animation.seek(self.local_time(cycle, 0.0));
+            }
+            Some(previous) if previous > cycle => {
+                self.animation.seek(self.local_time(previous, 0.0));
+                self.animation.seek(self.local_time(cycle, length));
+            }
+            _ => (),
+        }
+        self.animation.seek(self.local_time(cycle, local));
+    }
+}
+
+impl Debug for Repeat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Repeat")
+            .field("times", &self.times)
+            .field("yoyo", &self.yoyo)
+            .field("cycle", &self.cycle)
+            .finish()
+    }
+}
+
+/// Identifier of an animation played by [`Tweens`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TweenId(u64);
+
+struct Playing {
+    id: TweenId,
+    time: f32,
+    animation: Box<dyn Animation>,
+}
+
+/// Animations played by a scene
+///
+/// Return it from `Scene::tweens()` to have the manager advance it every frame, right before
+/// `Scene::on_update()`. Finished animations are dropped.
+#[derive(Default)]
+pub struct Tweens {
+    next_id: u64,
+    playing: Vec<Playing>,
+}
+
+impl Tweens {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start playing `animation`, its values are set to their starting point right away
+    pub fn play(&mut self, animation: impl Animation) -> TweenId {
+        let id = TweenId(self.next_id);
+        self.next_id += 1;
+        let mut animation = Box::new(animation);
+        animation.seek(0.0);
+        self.playing.push(Playing {
+            id,
+            time: 0.0,
+            animation,
+        });
+        id
+    }
+
+    /// Stop an animation, leaving its values where they are
+    pub fn stop(&mut self, id: TweenId) {
+        self.playing.retain(|playing| playing.id != id);
+    }
+
+    /// Stop every animation
+    pub fn clear(&mut self) {
+        self.playing.clear();
+    }
+
+    /// Check whether an animation is still playing
+    pub fn is_playing(&self, id: TweenId) -> bool {
+        self.playing.iter().any(|playing| playing.id == id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.playing.is_empty()
+    }
+
+    /// Move every animation `dt` seconds ahead
+    pub fn advance(&mut self, dt: f32) {
+        for playing in self.playing.iter_mut() {
+            playing.time += dt;
+            playing.animation.seek(playing.time);
+        }
+        self.playing
+            .retain(|playing| playing.time < playing.animation.duration());
+    }
+}
+
+impl Debug for Tweens {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.playing
+                    .iter()
+                    .map(|playing| (playing.id, playing.time)),
+            )
+            .finish()
+    }
+}
+
+/*******************************************************************************
+ * Tests
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES: [Ease; 31] = [
+        Ease::Linear,
+        Ease::QuadIn,
+        Ease::QuadOut,
+        Ease::QuadInOut,
+        Ease::CubicIn,
+        Ease::CubicOut,
+        Ease::CubicInOut,
+        Ease::QuartIn,
+        Ease::QuartOut,
+        Ease::QuartInOut,
+        Ease::QuintIn,
+        Ease::QuintOut,
+        Ease::QuintInOut,
+        Ease::SineIn,
+        Ease::SineOut,
+        Ease::SineInOut,
+        Ease::ExpoIn,
+        Ease::ExpoOut,
+        Ease::ExpoInOut,
+        Ease::CircIn,
+        Ease::CircOut,
+        Ease::CircInOut,
+        Ease::BackIn,
+        Ease::BackOut,
+        Ease::BackInOut,
+        Ease::ElasticIn,
+        Ease::ElasticOut,
+        Ease::ElasticInOut,
+        Ease::BounceIn,
+        Ease::BounceOut,
+        Ease::BounceInOut,
+    ];
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn it_should_start_and_end_every_curve_in_place() {
+        for ease in CURVES {
+            assert!(close(ease.apply(0.0), 0.0), "{:?} starts off", ease);
+            assert!(close(ease.apply(1.0), 1.0), "{:?} ends off", ease);
+        }
+    }
+
+    #[test]
+    fn it_should_meet_in_the_middle_of_symmetric_curves() {
+        for ease in CURVES
+            .iter()
+            .filter(|ease| format!("{:?}", ease).ends_with("InOut"))
+        {
+            assert!(close(ease.apply(0.5), 0.5), "{:?} is off", ease);
+        }
+        assert!(close(Ease::Linear.apply(0.25), 0.25));
+        assert!(close(Ease::QuadIn.apply(0.5), 0.25));
+        assert!(close(Ease::QuadOut.apply(0.5), 0.75));
+        assert!(Ease::BackIn.apply(0.2) < 0.0);
+    }
+
+    #[test]
+    fn it_should_interpolate_values() {
+        let from = Rectangle {
+            x: 0.0,
+            y: 10.0,
+            width: 100.0,
+            height: 0.0,
+        };
+        let to = Rectangle {
+            x: 10.0,
+            y: 10.0,
+            width: 50.0,
+            height: 20.0,
+        };
+        let rec = from.lerp(to, 0.5);
+        assert_eq!(
+            (rec.x, rec.y, rec.width, rec.height),
+            (5.0, 10.0, 75.0, 10.0)
+        );
+        let vec = Vector3::ZERO.lerp(Vector3::ONE, 0.25);
+        assert_eq!((vec.x, vec.y, vec.z), (0.25, 0.25, 0.25));
+        let color = Color::BLACK.lerp(Color::WHITE, 0.5);
+        assert_eq!((color.r, color.g, color.b, color.a), (128, 128, 128, 255));
+        let color = Color::BLACK.lerp(Color::WHITE, 1.5);
+        assert_eq!((color.r, color.g, color.b, color.a), (255, 255, 255, 255));
+    }
+
+    #[test]
+    fn it_should_tween_from_the_current_value() {
+        let value = Tweened::new(10.0);
+        let mut tweens = Tweens::new();
+        let id = tweens.play(Tween::new(&value, 20.0, 1.0));
+        assert_eq!(value.get(), 10.0);
+        tweens.advance(0.25);
+        assert_eq!(value.get(), 12.5);
+        tweens.advance(1.0);
+        assert_eq!(value.get(), 20.0);
+        assert!(!tweens.is_playing(id));
+        assert!(tweens.is_empty());
+    }
+
+    #[test]
+    fn it_should_play_sequences_in_order() {
+        let value = Tweened::new(Vector2::ZERO);
+        let one = Vector2::ONE;
+        let mut tweens = Tweens::new();
+        tweens.play(
+            Sequence::new()
+                .with(Tween::new(&value, one, 1.0))
+                .delay(1.0)
+                .with(Tween::new(&value, Vector2::ZERO, 1.0)),
+        );
+        tweens.advance(0.5);
+        assert_eq!((value.get().x, value.get().y), (0.5, 0.5));
+        tweens.advance(1.0);
+        assert_eq!((value.get().x, value.get().y), (1.0, 1.0));
+        tweens.advance(0.75);
+        assert_eq!((value.get().x, value.get().y), (0.75, 0.75));
+        tweens.advance(0.75);
+        assert_eq!((value.get().x, value.get().y), (0.0, 0.0));
+        assert!(tweens.is_empty());
+    }
+
+    #[test]
+    fn it_should_play_parallel_animations() {
+        let x = Tweened::new(0.0);
+        let y = Tweened::new(0.0);
+        let mut tweens = Tweens::new();
+        tweens.play(
+            Parallel::new()
+                .with(Tween::new(&x, 1.0, 1.0))
+                .with(Tween::new(&y, 1.0, 2.0)),
+        );
+        tweens.advance(1.0);
+        assert_eq!((x.get(), y.get()), (1.0, 0.5));
+        tweens.advance(0.5);
+        assert_eq!((x.get(), y.get()), (1.0, 0.75));
+        assert!(!tweens.is_empty());
+        tweens.advance(0.5);
+        assert!(tweens.is_empty());
+    }
+
+    #[test]
+    fn it_should_repeat_back_and_forth() {
+        let value = Tweened::new(0.0);
+        let mut tweens = Tweens::new();
+        tweens.play(Tween::new(&value, 1.0, 1.0).from(0.0).yoyo().repeat(2));
+        let values = (0..8)
+            .map(|_| {
+                tweens.advance(0.5);
+                value.get()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![0.5, 1.0, 0.5, 0.0, 0.5, 1.0, 0.5, 0.0]);
+        assert!(tweens.is_empty());
+    }
+
+    #[test]
+    fn it_should_wrap_repeated_sequences() {
+        let value = Tweened::new(0.0);
+        let mut tweens = Tweens::new();
+        tweens.play(
+            Sequence::new()
+                .with(Tween::new(&value, 1.0, 1.0).from(0.0))
+                .with(Tween::new(&value, 3.0, 1.0))
+                .forever(),
+        );
+        tweens.advance(1.5);
+        assert_eq!(value.get(), 2.0);
+        tweens.advance(1.0);
+        assert_eq!(value.get(), 0.5);
+        tweens.advance(1.0);
+        assert_eq!(value.get(), 2.0);
+        assert!(!tweens.is_empty());
+    }
+
+    #[test]
+    fn it_should_call_back_on_completion() {
+        let calls = Rc::new(Cell::new(0));
+        let value = Tweened::new(0.0);
+        let mut tweens = Tweens::new();
+        let counter = calls.clone();
+        tweens.play(
+            Tween::new(&value, 1.0, 1.0)
+                .then(move || counter.set(counter.get() + 1))
+                .yoyo(),
+        );
+        tweens.advance(0.5);
+        assert_eq!(calls.get(), 0);
+        tweens.advance(0.5);
+        assert_eq!(calls.get(), 1);
+        tweens.advance(1.0);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(value.get(), 0.0);
+    }
+
+    #[test]
+    fn it_should_stop_animations() {
+        let value = Tweened::new(0.0);
+        let mut tweens = Tweens::new();
+        let id = tweens.play(Tween::new(&value, 1.0, 1.0));
+        let other = tweens.play(Delay(1.0));
+        tweens.advance(0.5);
+        tweens.stop(id);
+        tweens.advance(0.25);
+        assert_eq!(value.get(), 0.5);
+        assert!(!tweens.is_playing(id));
+        assert!(tweens.is_playing(other));
+    }
+}